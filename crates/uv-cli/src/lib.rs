@@ -4780,7 +4780,7 @@ pub struct ExportArgs {
     /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
     ///
     /// uv will infer the output format from the file extension of the output file, if
-    /// provided. Otherwise, defaults to `requirements.txt`.
+    /// provided (e.g., CycloneDX for `.json`). Otherwise, defaults to `requirements.txt`.
     #[arg(long, value_enum)]
     pub format: Option<ExportFormat>,
 
@@ -38,6 +38,13 @@ impl VersionInfo {
             commit_info: None,
         }
     }
+
+    /// The full hash of the git commit that uv was built from, if known.
+    pub fn commit_hash(&self) -> Option<&str> {
+        self.commit_info
+            .as_ref()
+            .map(|commit_info| commit_info.commit_hash.as_str())
+    }
 }
 
 impl fmt::Display for VersionInfo {
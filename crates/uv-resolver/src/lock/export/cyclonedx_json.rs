@@ -1,32 +1,72 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::io::{self, Write};
 use std::path::Path;
 
-use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::errors::{JsonReadError, JsonWriteError};
+use cyclonedx_bom::external_models::uri::Uri as Url;
+use cyclonedx_bom::models::bom::{BomReference, SpecVersion, UrnUuid};
+use cyclonedx_bom::models::component::{
+    Classification, ComponentEvidence, ConfidenceScore, Identity, IdentityField, Method, Methods,
+    MimeType, Pedigree, Swid, ToolsReferences,
+};
+use cyclonedx_bom::models::composition::{AggregateType, Composition, Compositions};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::external_reference::{
+    BomLink, ExternalReference, ExternalReferenceType, ExternalReferences, Uri,
+};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use cyclonedx_bom::models::lifecycle::{Lifecycle, Lifecycles, Phase};
 use cyclonedx_bom::models::metadata::Metadata;
+use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
 use cyclonedx_bom::models::property::{Properties, Property};
+use cyclonedx_bom::models::signature::Signature;
 use cyclonedx_bom::models::tool::{Tool, Tools};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+use cyclonedx_bom::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
 use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
+use cyclonedx_bom::validation::Validate;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use globset::GlobSet;
+use indexmap::IndexMap;
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
 
 use uv_configuration::{
-    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+    DependencyGroupsWithDefaults, EditableMode, ExtrasSpecificationWithDefaults, InstallOptions,
 };
+use uv_distribution_filename::{DistExtension, SourceDistExtension};
+use uv_distribution_types::RequirementSource;
 use uv_fs::PortablePath;
-use uv_normalize::PackageName;
-use uv_pep508::MarkerTree;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
+use uv_platform_tags::Tags;
 use uv_preview::{Preview, PreviewFeature};
+use uv_pypi_types::HashDigest;
 use uv_warnings::warn_user;
 
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
-use crate::{Installable, LockError};
+use crate::lock::{
+    GitSourceKind, LockErrorKind, Package, PackageId, RegistrySource, Source, SourceDist,
+    TagPolicy, WheelWireSource,
+};
+use crate::{Installable, Lock, LockError};
+
+/// Re-exported so that consumers can inspect and modify an [`SbomExport`] without depending on
+/// `cyclonedx-bom` directly.
+pub use cyclonedx_bom;
 
-/// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
+/// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>),
+/// with the addition of `!`, which separates the epoch of a Python version (e.g., `1!2.3`).
 const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b' ')
+    .add(b'!')
     .add(b'"')
     .add(b'#')
     .add(b'%')
@@ -46,12 +86,164 @@ const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'^')
     .add(b'|');
 
+/// Packages that are exported with the `framework` component type, rather than `library`.
+const WELL_KNOWN_FRAMEWORKS: &[&str] = &[
+    "django",
+    "falcon",
+    "fastapi",
+    "flask",
+    "pyramid",
+    "sanic",
+    "starlette",
+    "tornado",
+];
+
+/// The uv-specific properties attached to the SBOM, namespaced as `uv:<namespace>:<name>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UvProperty<'a> {
+    /// The kind of source the package was resolved from (e.g., `registry` or `git`), set on every
+    /// package component.
+    SourceType,
+    /// The path of a workspace member, relative to the workspace root.
+    WorkspacePath,
+    /// Set on the package that is the root of the export.
+    IsProjectRoot,
+    /// Set on a component that stands in for the workspace, rather than a package.
+    IsSyntheticRoot,
+    /// Set on packages that are installed in editable mode.
+    IsEditable,
+    /// Set on packages without an integrity hash, when requested.
+    IsUnhashed,
+    /// Set on packages that are direct dependencies of the roots, rather than transitive ones.
+    ///
+    /// Only ever recorded as `true`: transitive dependencies omit the property.
+    IsDirect,
+    /// The marker under which the package is installed.
+    Marker,
+    /// The dependency groups through which the package is included.
+    Groups,
+    /// Whether the package is required at runtime (`runtime`), or only through dependency groups
+    /// (`dev`).
+    Category,
+    /// A version specifier with which a direct dependency was requested.
+    RequestedSpecifier,
+    /// The tags of the wheel that would be installed for the package.
+    WheelTag,
+    /// The URL from which the source distribution of a registry package is downloaded.
+    DownloadUrl,
+    /// When the release of a registry package was uploaded to the index.
+    UploadTime,
+    /// The commit of a Git dependency.
+    GitCommit,
+    /// The branch, tag or revision requested for a Git dependency.
+    GitReference,
+    /// The subdirectory of a Git dependency.
+    GitSubdirectory,
+    /// The hash of the distribution with the given filename.
+    Artifact(&'a str),
+    /// An install option with which the SBOM was generated (e.g., `no_project`).
+    InstallOption(&'a str),
+    /// The commit that the generating uv binary was built from.
+    ToolCommit,
+    /// The range of Python versions supported by the lockfile.
+    RequiresPython,
+    /// The hash of the lockfile from which the SBOM was generated.
+    LockHash,
+    /// The cutoff before which packages were uploaded, if the lockfile was resolved with
+    /// `exclude-newer`.
+    ExcludeNewer,
+    /// The version of uv that generated the SBOM.
+    BuildVersion,
+    /// The operating system on which the SBOM was generated (e.g., `linux`).
+    BuildOs,
+    /// The architecture on which the SBOM was generated (e.g., `x86_64`).
+    BuildArch,
+    /// Whether the SBOM covers every supported environment (`universal`), or a single environment
+    /// (`platform`).
+    BuildResolution,
+    /// Set on merged components that share a PURL with a component of different content.
+    DuplicateVersion,
+}
+
+impl UvProperty<'_> {
+    /// Create a property with the given value.
+    fn with_value(self, value: &str) -> Property {
+        Property::new(self, value)
+    }
+}
+
+impl std::fmt::Display for UvProperty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourceType => f.write_str("uv:package:source_type"),
+            Self::WorkspacePath => f.write_str("uv:workspace:path"),
+            Self::IsProjectRoot => f.write_str("uv:package:is_project_root"),
+            Self::IsSyntheticRoot => f.write_str("uv:package:is_synthetic_root"),
+            Self::IsEditable => f.write_str("uv:package:is_editable"),
+            Self::IsUnhashed => f.write_str("uv:package:is_unhashed"),
+            Self::IsDirect => f.write_str("uv:package:is_direct"),
+            Self::Marker => f.write_str("uv:package:marker"),
+            Self::Groups => f.write_str("uv:package:groups"),
+            Self::Category => f.write_str("uv:package:category"),
+            Self::RequestedSpecifier => f.write_str("uv:package:requested_specifier"),
+            Self::WheelTag => f.write_str("uv:package:wheel_tag"),
+            Self::DownloadUrl => f.write_str("uv:package:download_url"),
+            Self::UploadTime => f.write_str("uv:package:upload_time"),
+            Self::GitCommit => f.write_str("uv:git:commit"),
+            Self::GitReference => f.write_str("uv:git:reference"),
+            Self::GitSubdirectory => f.write_str("uv:git:subdirectory"),
+            Self::Artifact(filename) => write!(f, "uv:artifact:{filename}"),
+            Self::InstallOption(option) => write!(f, "uv:install:{option}"),
+            Self::ToolCommit => f.write_str("uv:tool:commit"),
+            Self::RequiresPython => f.write_str("uv:lock:requires_python"),
+            Self::LockHash => f.write_str("uv:lock:hash"),
+            Self::ExcludeNewer => f.write_str("uv:lock:exclude_newer"),
+            Self::BuildVersion => f.write_str("uv:build:version"),
+            Self::BuildOs => f.write_str("uv:build:os"),
+            Self::BuildArch => f.write_str("uv:build:arch"),
+            Self::BuildResolution => f.write_str("uv:build:resolution"),
+            Self::DuplicateVersion => f.write_str("uv:package:duplicate_version"),
+        }
+    }
+}
+
 /// Creates `CycloneDX` components, registering them in a `HashMap` so that they can be retrieved by `PackageId`.
 /// Also ensures uniqueness when generating bom-refs by using a numeric prefix which is incremented for each component.
 #[derive(Default)]
 struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
     package_to_component_map: HashMap<&'a PackageId, Component>,
+    editable: Option<EditableMode>,
+    /// Notes describing the overrides applied to each package, recorded in its pedigree.
+    overrides: FxHashMap<&'a PackageName, String>,
+    /// The direct dependencies of the roots.
+    direct_dependencies: FxHashSet<&'a PackageId>,
+    /// The version specifiers with which each direct dependency was requested.
+    requested_specifiers: FxHashMap<&'a PackageId, BTreeSet<String>>,
+    /// Whether to generate a SWID tag for each component.
+    swid: bool,
+    /// Whether to include the hashes of each component's distributions.
+    hashes: bool,
+    /// Whether to mark packages without an integrity hash.
+    mark_unhashed: bool,
+    /// References to the external BOMs describing each package, if any.
+    bom_links: FxHashMap<&'a PackageName, ExternalReference>,
+    /// References to the release notes of each package, if any.
+    release_notes: FxHashMap<&'a PackageId, ExternalReference>,
+    /// The copyright notice of each package, if any.
+    copyrights: FxHashMap<&'a PackageId, String>,
+    /// The platform tags with which to select the wheel that would be installed for each package.
+    tags: Option<&'a Tags>,
+    /// Whether to record the download URL of each registry package's source distribution.
+    download_urls: bool,
+    /// Whether to record when each registry package was uploaded.
+    upload_times: bool,
+    /// The packages required at runtime, if the category of each package should be recorded.
+    runtime_packages: Option<FxHashSet<&'a PackageId>>,
+    /// Whether to declare the distributions of each registry package as nested components.
+    distribution_components: bool,
+    /// Additional qualifiers to append to every PURL.
+    purl_qualifiers: Option<&'a BTreeMap<String, String>>,
 }
 
 impl<'a> ComponentBuilder<'a> {
@@ -80,15 +272,58 @@ impl<'a> ComponentBuilder<'a> {
         package.id.name.as_str()
     }
 
+    /// Determine the `CycloneDX` component type of a package.
+    ///
+    /// The lockfile doesn't record entry points or other metadata from which to infer the type, so
+    /// well-known frameworks are identified by name, falling back to `library`.
+    fn get_component_type(package: &Package) -> Classification {
+        if WELL_KNOWN_FRAMEWORKS.contains(&package.id.name.as_str()) {
+            Classification::Framework
+        } else {
+            Classification::Library
+        }
+    }
+
+    /// Extract the source type of a package, named as in the lockfile (e.g., `registry` or `git`).
+    fn get_source_type(package: &Package) -> &'static str {
+        SourceKind::from(&package.id.source).as_str()
+    }
+
+    /// Group packages from a private index or a Git repository by the host they were retrieved
+    /// from. Packages from PyPI, or from local sources, are left ungrouped.
+    fn get_group(package: &Package) -> Option<String> {
+        let url = match &package.id.source {
+            Source::Registry(RegistrySource::Url(url))
+                if !url.as_ref().starts_with("https://pypi.org/") =>
+            {
+                url
+            }
+            Source::Git(url, _) => url,
+            Source::Registry(_)
+            | Source::Direct(_, _)
+            | Source::Path(_)
+            | Source::Directory(_)
+            | Source::Editable(_)
+            | Source::Virtual(_) => return None,
+        };
+        url.to_url().ok()?.host_str().map(ToString::to_string)
+    }
+
     /// Generate a Package URL (purl) from a package. Returns `None` for local sources.
-    fn create_purl(package: &Package) -> Option<String> {
+    ///
+    /// The given qualifiers are appended, unless they conflict with a qualifier derived from the
+    /// package's source.
+    fn create_purl(
+        package: &Package,
+        extra_qualifiers: Option<&BTreeMap<String, String>>,
+    ) -> Option<String> {
         let name = percent_encode(Self::get_package_name(package).as_bytes(), PURL_ENCODE_SET);
 
         let version = Self::get_version_string(package)
             .map(|v| format!("@{}", percent_encode(v.as_bytes(), PURL_ENCODE_SET)))
             .unwrap_or_default();
 
-        let (purl_type, qualifiers) = match &package.id.source {
+        let (purl_type, mut qualifiers) = match &package.id.source {
             // By convention all Python packages use the "pypi" purl type, regardless of their source. For packages
             // from non-default repositories, we add a qualifier to indicate their source explicitly.
             // See the specs at
@@ -116,6 +351,12 @@ impl<'a> ComponentBuilder<'a> {
             }
         };
 
+        for (key, value) in extra_qualifiers.into_iter().flatten() {
+            if !qualifiers.iter().any(|(existing, _)| *existing == key) {
+                qualifiers.push((key, value));
+            }
+        }
+
         let qualifiers = if qualifiers.is_empty() {
             String::new()
         } else {
@@ -125,9 +366,237 @@ impl<'a> ComponentBuilder<'a> {
         Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
     }
 
+    /// Creates a SWID tag, for consumers that identify software by SWID rather than PURL or CPE.
+    ///
+    /// The tag ID is derived from the package name and version, in the format
+    /// `{package_name}-{version}`, or `{package_name}` if no version is provided.
+    fn create_swid(name: &str, version: Option<&str>) -> Swid {
+        Swid {
+            tag_id: if let Some(version) = version {
+                format!("{name}-{version}")
+            } else {
+                name.to_string()
+            },
+            name: name.to_string(),
+            version: version.map(ToString::to_string),
+            tag_version: None,
+            patch: None,
+            text: None,
+            url: None,
+        }
+    }
+
+    /// Converts a distribution hash to a `CycloneDX` hash.
+    fn create_hash(digest: &HashDigest) -> Hash {
+        let alg = match digest.algorithm {
+            uv_pypi_types::HashAlgorithm::Md5 => HashAlgorithm::MD5,
+            uv_pypi_types::HashAlgorithm::Sha256 => HashAlgorithm::SHA_256,
+            uv_pypi_types::HashAlgorithm::Sha384 => HashAlgorithm::SHA_384,
+            uv_pypi_types::HashAlgorithm::Sha512 => HashAlgorithm::SHA_512,
+            uv_pypi_types::HashAlgorithm::Blake2b => HashAlgorithm::BLAKE2b_512,
+        };
+        Hash {
+            alg,
+            content: HashValue(digest.digest.to_string()),
+        }
+    }
+
+    /// Declare each distribution of a registry package (i.e., its source distribution and each of
+    /// its wheels) as a `file` component, with its own hash and download URL, such that the
+    /// artifact that was installed can be reconciled with the package it belongs to.
+    ///
+    /// The bom-ref of each distribution is derived from that of the package (e.g.,
+    /// `idna-2@3.6#idna-3.6.tar.gz`), while its `mime-type` is derived from its filename.
+    fn create_distribution_components(package: &Package, bom_ref: &str) -> Vec<Component> {
+        let sdist = package.sdist.as_ref().and_then(|sdist| {
+            Some((
+                sdist.filename()?.to_string(),
+                sdist.url(),
+                sdist.hash().map(|hash| &hash.0),
+            ))
+        });
+        let wheels = package.wheels.iter().map(|wheel| {
+            let url = match &wheel.url {
+                WheelWireSource::Url { url } => Some(url),
+                WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+            };
+            (
+                wheel.filename.to_string(),
+                url,
+                wheel.hash.as_ref().map(|hash| &hash.0),
+            )
+        });
+        sdist
+            .into_iter()
+            .chain(wheels)
+            .map(|(filename, url, digest)| {
+                let hashes = digest.map(|digest| Hashes(vec![Self::create_hash(digest)]));
+                let mut component = Component::new(
+                    Classification::File,
+                    &filename,
+                    &Self::get_version_string(package).unwrap_or_default(),
+                    Some(format!("{bom_ref}#{filename}")),
+                );
+                component.version = Self::get_version_string(package)
+                    .as_deref()
+                    .map(NormalizedString::new);
+                component.external_references = url.map(|url| {
+                    ExternalReferences(vec![ExternalReference {
+                        hashes: hashes.clone(),
+                        ..ExternalReference::new(
+                            ExternalReferenceType::Distribution,
+                            Url::new(url.as_ref()),
+                        )
+                    }])
+                });
+                component.mime_type =
+                    Self::get_mime_type(&filename).map(|mime_type| MimeType(mime_type.to_string()));
+                component.hashes = hashes;
+                component
+            })
+            .collect()
+    }
+
+    /// Returns the media type of a distribution with the given filename (e.g., `application/zip`
+    /// for a wheel), if known.
+    fn get_mime_type(filename: &str) -> Option<&'static str> {
+        let mime_type = match DistExtension::from_path(filename).ok()? {
+            DistExtension::Wheel | DistExtension::Source(SourceDistExtension::Zip) => {
+                "application/zip"
+            }
+            DistExtension::Source(SourceDistExtension::Tar) => "application/x-tar",
+            DistExtension::Source(SourceDistExtension::TarGz | SourceDistExtension::Tgz) => {
+                "application/gzip"
+            }
+            DistExtension::Source(SourceDistExtension::TarBz2 | SourceDistExtension::Tbz) => {
+                "application/x-bzip2"
+            }
+            DistExtension::Source(SourceDistExtension::TarXz | SourceDistExtension::Txz) => {
+                "application/x-xz"
+            }
+            DistExtension::Source(SourceDistExtension::TarLz | SourceDistExtension::Tlz) => {
+                "application/x-lzip"
+            }
+            DistExtension::Source(SourceDistExtension::TarLzma) => "application/x-lzma",
+            DistExtension::Source(SourceDistExtension::TarZst) => "application/zstd",
+        };
+        Some(mime_type)
+    }
+
+    /// Creates the `evidence` for a component whose identity is attested by the hashes recorded in
+    /// the lockfile.
+    fn create_hash_evidence() -> ComponentEvidence {
+        ComponentEvidence {
+            licenses: None,
+            copyright: None,
+            occurrences: None,
+            callstack: None,
+            identity: Some(Identity {
+                field: IdentityField::Purl,
+                confidence: Some(ConfidenceScore::new(1.0)),
+                methods: Some(Methods(vec![Method {
+                    technique: "hash-comparison".to_string(),
+                    confidence: ConfidenceScore::new(1.0),
+                    value: None,
+                }])),
+                tools: Some(ToolsReferences(vec![TOOL_BOM_REF.to_string()])),
+            }),
+        }
+    }
+
+    /// Returns the tags (e.g., `cp312-cp312-manylinux_2_17_x86_64`) of the wheel that would be
+    /// installed for a registry package on the target platform, if any.
+    ///
+    /// Packages that would be built from a source distribution, or that aren't retrieved from a
+    /// registry, have no such wheel.
+    fn get_wheel_tag(&self, package: &Package) -> Option<String> {
+        let tags = self.tags?;
+        if !matches!(package.id.source, Source::Registry(_)) {
+            return None;
+        }
+        let wheel = &package.wheels[package.find_best_wheel(TagPolicy::Required(tags))?];
+        Some(format!(
+            "{}-{}-{}",
+            wheel.filename.python_tags().iter().join("."),
+            wheel.filename.abi_tags().iter().join("."),
+            wheel.filename.platform_tags().iter().join("."),
+        ))
+    }
+
+    /// Returns when the release of a registry package was uploaded, i.e., the upload time of its
+    /// earliest distribution, if the index reported one.
+    fn get_upload_time(package: &Package) -> Option<jiff::Timestamp> {
+        if !matches!(package.id.source, Source::Registry(_)) {
+            return None;
+        }
+        package
+            .sdist
+            .as_ref()
+            .and_then(SourceDist::upload_time)
+            .into_iter()
+            .chain(package.wheels.iter().filter_map(|wheel| wheel.upload_time))
+            .min()
+    }
+
+    /// Returns the filename and hash of each of the package's distributions that has a hash.
+    fn get_artifact_hashes(package: &Package) -> Vec<(String, &HashDigest)> {
+        let sdist = package.sdist.as_ref().and_then(|sdist| {
+            let filename = sdist.filename()?;
+            let hash = sdist.hash()?;
+            Some((filename.to_string(), &hash.0))
+        });
+        let wheels = package.wheels.iter().filter_map(|wheel| {
+            let hash = wheel.hash.as_ref()?;
+            Some((wheel.filename.to_string(), &hash.0))
+        });
+        sdist.into_iter().chain(wheels).collect()
+    }
+
+    /// Reference each remote distribution by its download URL, along with its hash, such that the
+    /// artifact can be verified independently of the component's identity.
+    fn create_distribution_references(package: &Package) -> Vec<ExternalReference> {
+        let sdist = package.sdist.as_ref().and_then(|sdist| {
+            // Direct URL source distributions are only locked by their source.
+            let url = sdist.url().or(match &package.id.source {
+                Source::Direct(url, _) => Some(url),
+                _ => None,
+            })?;
+            Some((url, &sdist.hash()?.0))
+        });
+        let wheels = package.wheels.iter().filter_map(|wheel| match &wheel.url {
+            WheelWireSource::Url { url } => Some((url, &wheel.hash.as_ref()?.0)),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => None,
+        });
+        sdist
+            .into_iter()
+            .chain(wheels)
+            .map(|(url, digest)| ExternalReference {
+                hashes: Some(Hashes(vec![Self::create_hash(digest)])),
+                ..ExternalReference::new(
+                    ExternalReferenceType::Distribution,
+                    Url::new(url.as_ref()),
+                )
+            })
+            .collect()
+    }
+
+    /// Whether the given key is a valid PURL qualifier key in its canonical form, i.e., composed of
+    /// lowercase ASCII letters, digits, `.`, `-` and `_`, and not starting with a digit.
+    fn is_valid_qualifier_key(key: &str) -> bool {
+        key.chars().next().is_some_and(|c| !c.is_ascii_digit())
+            && key.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '-' | '_')
+            })
+    }
+
+    /// Formats the qualifiers of a PURL in their canonical form, i.e., sorted by key, with empty
+    /// values omitted and every value percent-encoded, such that identical packages always have
+    /// identical PURLs.
     fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
         let joined_qualifiers = qualifiers
             .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .sorted_by_key(|(key, _)| *key)
             .map(|(key, value)| {
                 format!(
                     "{key}={}",
@@ -143,21 +612,27 @@ impl<'a> ComponentBuilder<'a> {
         package: &'a Package,
         package_type: PackageType,
         marker: Option<&MarkerTree>,
+        groups: Option<&BTreeSet<&GroupName>>,
+        dependents: &[&Package],
     ) -> Component {
-        let component = self.create_component_from_package(package, package_type, marker);
+        let component =
+            self.create_component_from_package(package, package_type, marker, groups, dependents);
         self.package_to_component_map
             .insert(&package.id, component.clone());
         component
     }
 
-    fn create_synthetic_root_component(&mut self, root: Option<&Package>) -> Component {
-        let name = root.map(Self::get_package_name).unwrap_or("uv-workspace");
+    fn create_synthetic_root_component(
+        &mut self,
+        name: &str,
+        component_type: Classification,
+    ) -> Component {
         let bom_ref = self.create_bom_ref(name, None);
-        let properties = Properties(vec![Property::new("uv:package:is_synthetic_root", "true")]);
+        let properties = Properties(vec![UvProperty::IsSyntheticRoot.with_value("true")]);
 
-        // No need to register as we manually add dependencies in `if all_packages` check in `from_lock`
+        // No need to register as we manually add dependencies to the synthetic root
         Component {
-            component_type: Classification::Library,
+            component_type,
             name: NormalizedString::new(name),
             version: None,
             bom_ref: Some(bom_ref),
@@ -191,35 +666,181 @@ impl<'a> ComponentBuilder<'a> {
         package: &Package,
         package_type: PackageType,
         marker: Option<&MarkerTree>,
+        groups: Option<&BTreeSet<&GroupName>>,
+        dependents: &[&Package],
     ) -> Component {
         let name = Self::get_package_name(package);
         let version = Self::get_version_string(package);
         let bom_ref = self.create_bom_ref(name, version.as_deref());
-        let purl = Self::create_purl(package).and_then(|purl_string| purl_string.parse().ok());
-        let mut properties = vec![];
+        let purl = Self::create_purl(package, self.purl_qualifiers)
+            .and_then(|purl_string| purl_string.parse().ok());
+        let mut properties =
+            vec![UvProperty::SourceType.with_value(Self::get_source_type(package))];
 
         match package_type {
+            // Omit paths that can't be expressed relative to the workspace root (e.g., on another
+            // drive), which would leak machine-specific details into the SBOM.
             PackageType::Workspace(path) => {
-                properties.push(Property::new(
-                    "uv:workspace:path",
-                    &PortablePath::from(path).to_string(),
-                ));
+                if path.is_relative() {
+                    properties.push(
+                        UvProperty::WorkspacePath.with_value(&PortablePath::from(path).to_string()),
+                    );
+                }
             }
             PackageType::Root => {
-                properties.push(Property::new("uv:package:is_project_root", "true"));
+                properties.push(UvProperty::IsProjectRoot.with_value("true"));
             }
             PackageType::Dependency => {}
         }
 
+        // Editable packages are local, in-development sources, so mark them distinctly unless the
+        // export requests that they be treated as regular installs.
+        if matches!(package.id.source, Source::Editable(_))
+            && !matches!(self.editable, Some(EditableMode::NonEditable))
+        {
+            properties.push(UvProperty::IsEditable.with_value("true"));
+        }
+
+        if self.mark_unhashed && package.hashes().is_empty() {
+            properties.push(UvProperty::IsUnhashed.with_value("true"));
+        }
+
+        if self.direct_dependencies.contains(&package.id) {
+            properties.push(UvProperty::IsDirect.with_value("true"));
+        }
+
+        if let Source::Git(_, git) = &package.id.source {
+            properties.push(UvProperty::GitCommit.with_value(&git.precise.to_string()));
+            match &git.kind {
+                GitSourceKind::Tag(reference)
+                | GitSourceKind::Branch(reference)
+                | GitSourceKind::Rev(reference) => {
+                    properties.push(UvProperty::GitReference.with_value(reference));
+                }
+                GitSourceKind::DefaultBranch => {}
+            }
+            if let Some(subdirectory) = &git.subdirectory {
+                properties.push(
+                    UvProperty::GitSubdirectory
+                        .with_value(&PortablePath::from(subdirectory).to_string()),
+                );
+            }
+        }
+
         if let Some(marker_contents) = marker.and_then(|marker| marker.contents()) {
-            properties.push(Property::new(
-                "uv:package:marker",
-                &marker_contents.to_string(),
-            ));
+            properties.push(UvProperty::Marker.with_value(&marker_contents.to_string()));
+        }
+
+        if let Some(groups) = groups.filter(|groups| !groups.is_empty()) {
+            properties.push(UvProperty::Groups.with_value(&groups.iter().join(",")));
+        }
+
+        if let Some(runtime_packages) = &self.runtime_packages {
+            let category = if runtime_packages.contains(&package.id) {
+                "runtime"
+            } else {
+                "dev"
+            };
+            properties.push(UvProperty::Category.with_value(category));
         }
 
+        // Record the constraints that produced the pinned version of each direct dependency.
+        for specifier in self
+            .requested_specifiers
+            .get(&package.id)
+            .into_iter()
+            .flatten()
+        {
+            properties.push(UvProperty::RequestedSpecifier.with_value(specifier));
+        }
+
+        if let Some(wheel_tag) = self.get_wheel_tag(package) {
+            properties.push(UvProperty::WheelTag.with_value(&wheel_tag));
+        }
+
+        if self.download_urls
+            && matches!(package.id.source, Source::Registry(_))
+            && let Some(url) = package.sdist.as_ref().and_then(|sdist| sdist.url())
+        {
+            properties.push(UvProperty::DownloadUrl.with_value(url.as_ref()));
+        }
+
+        if self.upload_times
+            && let Some(upload_time) = Self::get_upload_time(package)
+        {
+            properties.push(UvProperty::UploadTime.with_value(&upload_time.to_string()));
+        }
+
+        // The component hashes don't identify the distribution they belong to, so record the hash
+        // of each distribution against its filename, such that a verifier can match the installed
+        // artifact to its exact digest.
+        let hashes = if self.hashes {
+            for (filename, digest) in Self::get_artifact_hashes(package) {
+                properties.push(UvProperty::Artifact(&filename).with_value(&digest.to_string()));
+            }
+            Some(Hashes(
+                package.hashes().iter().map(Self::create_hash).collect(),
+            ))
+            .filter(|hashes| !hashes.0.is_empty())
+        } else {
+            None
+        };
+
+        let external_references = self
+            .bom_links
+            .get(&package.id.name)
+            .cloned()
+            .into_iter()
+            .chain(self.release_notes.get(&package.id).cloned())
+            .chain(if self.hashes {
+                Self::create_distribution_references(package)
+            } else {
+                Vec::new()
+            })
+            .collect::<Vec<_>>();
+
+        // Components with hashes have their identity attested by the lockfile.
+        let evidence = hashes.as_ref().map(|_| Self::create_hash_evidence());
+
+        // Overridden packages may differ from what their dependents requested, so record the
+        // override in the pedigree.
+        let pedigree = self.overrides.get(&package.id.name).map(|notes| Pedigree {
+            ancestors: None,
+            descendants: None,
+            variants: None,
+            commits: None,
+            patches: None,
+            notes: Some(notes.clone()),
+        });
+
+        // With annotations enabled, describe which packages pulled in the component, as in the
+        // `# via` comments of an annotated `requirements.txt`.
+        let description = (!dependents.is_empty()).then(|| {
+            NormalizedString::new(&format!(
+                "via {}",
+                dependents
+                    .iter()
+                    .map(|dependent| &dependent.id.name)
+                    .join(", ")
+            ))
+        });
+
+        let swid = self
+            .swid
+            .then(|| Self::create_swid(name, version.as_deref()));
+
+        let components =
+            if self.distribution_components && matches!(package.id.source, Source::Registry(_)) {
+                Some(Components(Self::create_distribution_components(
+                    package, &bom_ref,
+                )))
+                .filter(|components| !components.0.is_empty())
+            } else {
+                None
+            };
+
         Component {
-            component_type: Classification::Library,
+            component_type: Self::get_component_type(package),
             name: NormalizedString::new(name),
             version: version.as_deref().map(NormalizedString::new),
             bom_ref: Some(bom_ref),
@@ -228,24 +849,33 @@ impl<'a> ComponentBuilder<'a> {
             supplier: None,
             author: None,
             publisher: None,
-            group: None,
-            description: None,
+            group: Self::get_group(package)
+                .as_deref()
+                .map(NormalizedString::new),
+            description,
             scope: None,
-            hashes: None,
+            hashes,
             licenses: None,
-            copyright: None,
+            copyright: self
+                .copyrights
+                .get(&package.id)
+                .map(|copyright| NormalizedString::new(copyright)),
             cpe: None,
-            swid: None,
+            swid,
             modified: None,
-            pedigree: None,
-            external_references: None,
+            pedigree,
+            external_references: if !external_references.is_empty() {
+                Some(ExternalReferences(external_references))
+            } else {
+                None
+            },
             properties: if !properties.is_empty() {
                 Some(Properties(properties))
             } else {
                 None
             },
-            components: None,
-            evidence: None,
+            components,
+            evidence,
             signature: None,
             model_card: None,
             data: None,
@@ -257,189 +887,8515 @@ impl<'a> ComponentBuilder<'a> {
     }
 }
 
-pub fn from_lock<'lock>(
-    target: &impl Installable<'lock>,
-    prune: &[PackageName],
-    extras: &ExtrasSpecificationWithDefaults,
-    groups: &DependencyGroupsWithDefaults,
-    annotate: bool,
-    install_options: &'lock InstallOptions,
-    preview: Preview,
-    all_packages: bool,
-) -> Result<Bom, LockError> {
-    if !preview.is_enabled(PreviewFeature::SbomExport) {
-        warn_user!(
-            "`uv export --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
-            PreviewFeature::SbomExport
-        );
-    }
-
-    // Extract the packages from the lock file.
-    let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
-        target,
-        prune,
-        extras,
-        groups,
-        annotate,
-        install_options,
-    )?;
-
-    nodes.sort_unstable_by_key(|node| &node.package.id);
-
-    // CycloneDX requires exactly one root component in `metadata.component`.
-    let root = match target.roots().collect::<Vec<_>>().as_slice() {
-        // Single root: use it directly
-        [single_root] => nodes
-            .iter()
-            .find(|node| &node.package.id.name == *single_root)
-            .map(|node| node.package),
-        // Multiple roots or no roots: use fallback
-        _ => None,
-    }
-    .or_else(|| target.lock().root()); // Fallback to project root
-
-    let mut component_builder = ComponentBuilder::default();
-
-    let mut metadata = Metadata {
-        component: root
-            .map(|package| component_builder.create_component(package, PackageType::Root, None)),
-        timestamp: cyclonedx_bom::prelude::DateTime::now().ok(),
-        tools: Some(Tools::List(vec![Tool {
-            vendor: Some(NormalizedString::new("Astral Software Inc.")),
-            name: Some(NormalizedString::new("uv")),
-            version: Some(NormalizedString::new(uv_version::version())),
-            hashes: None,
-            external_references: None,
-        }])),
-        ..Metadata::default()
-    };
+/// A `CycloneDX` software bill of materials (SBOM) generated from a [`Lock`](crate::Lock).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SbomExport {
+    bom: Bom,
+    /// Whether uv-specific properties are recorded, per [`SbomExportBuilder::with_uv_properties`].
+    uv_properties: bool,
+}
 
-    let workspace_member_ids = nodes
-        .iter()
-        .filter_map(|node| {
-            if target.lock().members().contains(&node.package.id.name) {
-                Some(&node.package.id)
-            } else {
-                None
-            }
-        })
-        .collect::<FxHashSet<_>>();
+impl SbomExport {
+    /// Return the underlying `CycloneDX` [`Bom`].
+    pub fn bom(&self) -> &Bom {
+        &self.bom
+    }
 
-    let mut components = nodes
-        .iter()
-        .filter(|node| root.is_none_or(|root_pkg| root_pkg.id != node.package.id)) // Filter out root package as this is included in `metadata`
-        .map(|node| {
-            let package_type = if workspace_member_ids.contains(&node.package.id) {
-                let path = match &node.package.id.source {
-                    Source::Path(path)
-                    | Source::Directory(path)
-                    | Source::Editable(path)
-                    | Source::Virtual(path) => path,
-                    Source::Registry(_) | Source::Git(_, _) | Source::Direct(_, _) => {
-                        // Workspace packages should always be local dependencies
-                        return Err(LockErrorKind::NonLocalWorkspaceMember {
-                            id: node.package.id.clone(),
-                        }
-                        .into());
-                    }
-                };
-                PackageType::Workspace(path)
-            } else {
-                PackageType::Dependency
-            };
-            Ok(component_builder.create_component(node.package, package_type, Some(&node.marker)))
-        })
-        .collect::<Result<Vec<_>, LockError>>()?;
-
-    let mut dependencies = create_dependencies(&nodes, &component_builder);
-
-    // Use a synthetic root in two cases:
-    // 1. With `--all-packages`: ensures no dangling components from workspace packages not depended on by the workspace root.
-    // 2. For virtual workspaces (no root project): provides an anchor for the dependency graph.
-    if all_packages || metadata.component.is_none() {
-        let synthetic_root = component_builder.create_synthetic_root_component(root);
-        let synthetic_root_bom_ref = synthetic_root
-            .bom_ref
-            .clone()
-            .expect("bom-ref should always exist");
-        let root = metadata.component.replace(synthetic_root);
-
-        let mut synthetic_root_deps = workspace_member_ids
+    /// Return a mutable reference to the underlying `CycloneDX` [`Bom`], e.g., to enrich the
+    /// document with additional properties before serializing it.
+    pub fn bom_mut(&mut self) -> &mut Bom {
+        &mut self.bom
+    }
+
+    /// Consume the export, returning the underlying `CycloneDX` [`Bom`].
+    pub fn into_bom(self) -> Bom {
+        self.bom
+    }
+
+    /// Iterate over the components, e.g., to write them in a custom format, without serializing
+    /// the document.
+    ///
+    /// The main component (in `metadata.component`) is not included.
+    pub fn components(&self) -> impl Iterator<Item = &Component> {
+        self.bom
+            .components
             .iter()
-            .filter_map(|c| component_builder.get_component(c))
-            .map(|c| c.bom_ref.clone().expect("bom-ref should always exist"))
-            .collect::<Vec<_>>();
-        if let Some(ref root_component) = root
-            && let Some(ref root_bom_ref) = root_component.bom_ref
+            .flat_map(|components| &components.0)
+    }
+
+    /// Iterate over the dependencies of each component, identified by bom-ref.
+    pub fn dependencies(&self) -> impl Iterator<Item = &Dependency> {
+        self.bom
+            .dependencies
+            .iter()
+            .flat_map(|dependencies| &dependencies.0)
+    }
+
+    /// Record the commit that the generating uv binary was built from, such that the exact
+    /// generator can be identified when auditing the SBOM.
+    ///
+    /// Has no effect if the SBOM was generated without uv properties.
+    pub fn set_tool_commit(&mut self, commit: &str) {
+        self.set_metadata_property(UvProperty::ToolCommit.with_value(commit));
+    }
+
+    /// Record a signature of the SBOM in JSON Signature Format (JSF), e.g., as computed by an
+    /// external signing step, such that the signature travels with the document rather than in a
+    /// sidecar file.
+    ///
+    /// uv doesn't compute or verify the signature itself.
+    pub fn set_signature(&mut self, signature: Signature) {
+        self.bom.signature = Some(signature);
+    }
+
+    /// Record the SHA-256 hash of the lockfile contents from which the SBOM was generated, such
+    /// that a verifier can confirm that the SBOM matches a lockfile without re-resolving it.
+    ///
+    /// Has no effect if the SBOM was generated without uv properties.
+    pub fn set_lock_hash(&mut self, lockfile: &[u8]) {
+        self.set_metadata_property(
+            UvProperty::LockHash.with_value(&format!("sha256:{:x}", Sha256::digest(lockfile))),
+        );
+    }
+
+    /// Record a uv property in the SBOM metadata, replacing any existing property of the same
+    /// name, unless the SBOM was generated without uv properties.
+    ///
+    /// If the serial number was derived from the contents of the SBOM, it's derived again, such
+    /// that it continues to identify the document.
+    fn set_metadata_property(&mut self, property: Property) {
+        if !self.uv_properties {
+            return;
+        }
+        let reproducible = self.bom.serial_number.is_some()
+            && content_serial_number(&self.bom).ok() == self.bom.serial_number;
+        let properties = &mut self
+            .bom
+            .metadata
+            .get_or_insert_default()
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .0;
+        if let Some(existing) = properties
+            .iter_mut()
+            .find(|existing| existing.name == property.name)
         {
-            synthetic_root_deps.push(root_bom_ref.clone());
+            *existing = property;
+        } else {
+            properties.push(property);
+        }
+        if reproducible {
+            self.bom.serial_number = content_serial_number(&self.bom).ok();
         }
+    }
+
+    /// Write the SBOM as a `CycloneDX` v1.5 JSON document.
+    pub fn output_as_json_v1_5<W: Write>(self, writer: &mut W) -> Result<(), SbomError> {
+        Ok(self.bom.output_as_json_v1_5(writer)?)
+    }
 
-        if let Some(workspace_root) = root {
-            components.push(workspace_root);
+    /// Write the SBOM as a `CycloneDX` JSON document conforming to the given version of the
+    /// specification, e.g., for scanners that only accept `CycloneDX` v1.4.
+    ///
+    /// Any data that can't be represented in an earlier version (e.g., `metadata.lifecycles`) is
+    /// omitted. Tools are declared in the legacy list form, which is all that versions before
+    /// v1.5 support.
+    pub fn output_as_json<W: Write>(
+        mut self,
+        writer: &mut W,
+        spec_version: SpecVersion,
+    ) -> Result<(), SbomError> {
+        if spec_version < SpecVersion::V1_5
+            && let Some(metadata) = self.bom.metadata.as_mut()
+            && let Some(Tools::Object { components, .. }) = &metadata.tools
+        {
+            metadata.tools = Some(Tools::List(legacy_tools(components.as_ref())));
         }
+        Ok(self.bom.output_as_json(writer, spec_version)?)
+    }
 
-        dependencies.push(Dependency {
-            dependency_ref: synthetic_root_bom_ref,
-            dependencies: synthetic_root_deps
-                .into_iter()
-                .sorted_unstable()
-                .unique()
-                .collect(),
-        });
+    /// Write the SBOM as a compact `CycloneDX` v1.5 JSON document, without any insignificant
+    /// whitespace, e.g., for embedding in container images or transmitting over the network.
+    pub fn output_as_compact_json_v1_5<W: Write>(self, writer: &mut W) -> Result<(), SbomError> {
+        let mut pretty = Vec::new();
+        self.bom.output_as_json_v1_5(&mut pretty)?;
+        writer
+            .write_all(&minify_json(&pretty))
+            .map_err(|err| JsonWriteError::from(serde_json::Error::io(err)))?;
+        Ok(())
     }
 
-    let bom = Bom {
-        metadata: Some(metadata),
-        components: Some(Components(components)),
-        dependencies: Some(Dependencies(dependencies)),
-        ..Bom::default()
-    };
+    /// Write the SBOM as a canonical `CycloneDX` v1.5 JSON document, with the keys of every object
+    /// sorted, such that SBOMs committed to a repository produce minimal diffs between generations.
+    pub fn output_as_canonical_json_v1_5<W: Write>(self, writer: &mut W) -> Result<(), SbomError> {
+        let mut pretty = Vec::new();
+        self.bom.output_as_json_v1_5(&mut pretty)?;
+        let mut value =
+            serde_json::from_slice::<serde_json::Value>(&pretty).map_err(JsonWriteError::from)?;
+        value.sort_all_objects();
+        serde_json::to_writer_pretty(writer, &value).map_err(JsonWriteError::from)?;
+        Ok(())
+    }
 
-    Ok(bom)
+    /// Write the SBOM as a gzip-compressed `CycloneDX` v1.5 JSON document (i.e., `.json.gz`).
+    ///
+    /// The document is streamed through the encoder rather than buffered in full.
+    pub fn output_as_gzipped_json_v1_5<W: Write>(self, writer: W) -> Result<W, SbomError> {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        self.bom.output_as_json_v1_5(&mut encoder)?;
+        Ok(encoder
+            .finish()
+            .map_err(|err| JsonWriteError::from(serde_json::Error::io(err)))?)
+    }
+
+    /// Parse a previously generated `CycloneDX` v1.5 JSON document.
+    pub fn from_json(json: &str) -> Result<Self, SbomError> {
+        let bom = Bom::parse_from_json_v1_5(json.as_bytes()).map_err(SbomError::InvalidSbom)?;
+        Ok(Self::from(bom))
+    }
 }
 
-fn create_dependencies(
-    nodes: &[ExportableRequirement<'_>],
-    component_builder: &ComponentBuilder,
-) -> Vec<Dependency> {
-    nodes
-        .iter()
-        .map(|node| {
-            let component = component_builder
-                .get_component(&node.package.id)
-                .expect("All nodes should have been added to map");
+impl SbomExport {
+    /// Merge multiple SBOMs into a single document, e.g., for a repository containing several
+    /// independent projects.
+    ///
+    /// Components are deduplicated by PURL and primary hash, such that a package present in multiple
+    /// inputs is listed once, while distinct versions of the same package are retained as separate
+    /// components. A component without a hash is considered identical to any component with the
+    /// same PURL. Components with the same PURL but different hashes (e.g., a version that was
+    /// republished) are genuinely different artifacts, so they're retained, and marked with a
+    /// `uv:package:duplicate_version` property. Components without a PURL (e.g., local projects)
+    /// are never deduplicated. The main component of each input becomes a regular component,
+    /// depended upon by a synthetic root `application` component.
+    ///
+    /// If the inputs were generated without uv properties, the merged SBOM omits them too, and
+    /// duplicate versions are retained without being marked. Inputs that disagree on whether uv
+    /// properties are recorded can't be merged, as the result would be inconsistent.
+    pub fn merge(exports: &[Self]) -> Result<Self, SbomError> {
+        let uv_properties = exports.first().is_none_or(|export| export.uv_properties);
+        if exports
+            .iter()
+            .any(|export| export.uv_properties != uv_properties)
+        {
+            return Err(SbomError::ConflictingUvProperties);
+        }
+        let mut component_builder = ComponentBuilder::default();
+        let mut components = Vec::new();
+        let mut dependencies: IndexMap<String, Vec<String>, FxBuildHasher> = IndexMap::default();
+        // The primary hash, index and bom-ref of each component with a given PURL.
+        let mut components_by_purl: FxHashMap<String, Vec<(Option<String>, usize, String)>> =
+            FxHashMap::default();
+        let mut root_deps = Vec::new();
+        let mut vulnerabilities: IndexMap<
+            Option<NormalizedString>,
+            (Vulnerability, Vec<String>),
+            FxBuildHasher,
+        > = IndexMap::default();
 
-            let immediate_deps = &node.package.dependencies;
-            let optional_deps = node.package.optional_dependencies.values().flatten();
-            let dep_groups = node.package.dependency_groups.values().flatten();
+        for export in exports {
+            // The bom-refs are only unique within each input, so reassign them as we go.
+            let mut bom_refs: FxHashMap<&str, String> = FxHashMap::default();
 
-            let package_deps = immediate_deps
+            let main_component = export
+                .bom
+                .metadata
+                .as_ref()
+                .and_then(|metadata| metadata.component.as_ref());
+            let package_components = export
+                .bom
+                .components
                 .iter()
-                .chain(optional_deps)
-                .chain(dep_groups)
-                .filter_map(|dep| component_builder.get_component(&dep.package_id));
+                .flat_map(|components| &components.0);
 
-            let bom_refs = package_deps
-                .map(|p| p.bom_ref.clone().expect("bom-ref should always exist"))
-                .sorted_unstable()
-                .unique()
-                .collect();
+            for component in main_component.into_iter().chain(package_components) {
+                let purl = component.purl.as_ref().map(ToString::to_string);
+                let hash = component
+                    .hashes
+                    .as_ref()
+                    .and_then(|hashes| hashes.0.first())
+                    .map(|hash| hash.content.0.clone());
+                let same_purl = purl
+                    .as_ref()
+                    .and_then(|purl| components_by_purl.get(purl))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                if let Some((_, _, bom_ref)) = same_purl.iter().find(|(existing, _, _)| {
+                    existing.is_none() || hash.is_none() || *existing == hash
+                }) {
+                    if let Some(original) = &component.bom_ref {
+                        bom_refs.insert(original, bom_ref.clone());
+                    }
+                    continue;
+                }
 
-            Dependency {
-                dependency_ref: component
-                    .bom_ref
-                    .clone()
-                    .expect("bom-ref should always exist"),
-                dependencies: bom_refs,
+                // Mark the components that share a PURL but differ in content.
+                let duplicate_version = uv_properties && !same_purl.is_empty();
+                if duplicate_version {
+                    for (_, index, _) in same_purl {
+                        mark_duplicate_version(&mut components[*index]);
+                    }
+                }
+
+                let bom_ref = component_builder.create_bom_ref(
+                    &component.name,
+                    component.version.as_ref().map(AsRef::as_ref),
+                );
+                if let Some(original) = &component.bom_ref {
+                    bom_refs.insert(original, bom_ref.clone());
+                }
+                if let Some(purl) = purl {
+                    components_by_purl.entry(purl).or_default().push((
+                        hash,
+                        components.len(),
+                        bom_ref.clone(),
+                    ));
+                }
+                let mut component = Component {
+                    bom_ref: Some(bom_ref),
+                    ..component.clone()
+                };
+                if duplicate_version {
+                    mark_duplicate_version(&mut component);
+                }
+                components.push(component);
+            }
+
+            if let Some(bom_ref) = main_component
+                .and_then(|component| component.bom_ref.as_deref())
+                .and_then(|bom_ref| bom_refs.get(bom_ref))
+            {
+                root_deps.push(bom_ref.clone());
+            }
+
+            for dependency in export
+                .bom
+                .dependencies
+                .iter()
+                .flat_map(|dependencies| &dependencies.0)
+            {
+                let Some(dependency_ref) = bom_refs.get(dependency.dependency_ref.as_str()) else {
+                    continue;
+                };
+                dependencies
+                    .entry(dependency_ref.clone())
+                    .or_default()
+                    .extend(
+                        dependency
+                            .dependencies
+                            .iter()
+                            .filter_map(|bom_ref| bom_refs.get(bom_ref.as_str()))
+                            .cloned(),
+                    );
             }
+
+            // Vulnerabilities reported by several inputs are combined, targeting the reassigned
+            // bom-refs of every affected component.
+            for vulnerability in export
+                .bom
+                .vulnerabilities
+                .iter()
+                .flat_map(|vulnerabilities| &vulnerabilities.0)
+            {
+                vulnerabilities
+                    .entry(vulnerability.id.clone())
+                    .or_insert_with(|| (vulnerability.clone(), Vec::new()))
+                    .1
+                    .extend(
+                        vulnerability
+                            .vulnerability_targets
+                            .iter()
+                            .flat_map(|targets| &targets.0)
+                            .filter_map(|target| bom_refs.get(target.bom_ref.as_str()))
+                            .cloned(),
+                    );
+            }
+        }
+
+        let root = component_builder
+            .create_synthetic_root_component("uv-merged-sbom", Classification::Application);
+        let root_bom_ref = root.bom_ref.clone().expect("bom-ref should always exist");
+
+        let dependencies = std::iter::once(Dependency {
+            dependency_ref: root_bom_ref,
+            dependencies: root_deps.into_iter().sorted_unstable().unique().collect(),
         })
-        .collect()
-}
+        .chain(
+            dependencies
+                .into_iter()
+                .map(|(dependency_ref, dependencies)| Dependency {
+                    dependency_ref,
+                    dependencies: dependencies
+                        .into_iter()
+                        .sorted_unstable()
+                        .unique()
+                        .collect(),
+                }),
+        )
+        .collect();
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum PackageType<'a> {
-    Root,
-    Workspace(&'a Path),
-    Dependency,
+        let metadata = Metadata {
+            component: Some(root),
+            timestamp: SbomTimestamp::Now.to_date_time(),
+            tools: Some(create_tools(true, &[])),
+            lifecycles: Some(create_lifecycles()),
+            ..Metadata::default()
+        };
+
+        let bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            vulnerabilities: Some(Vulnerabilities(
+                vulnerabilities
+                    .into_values()
+                    .map(|(vulnerability, bom_refs)| Vulnerability {
+                        vulnerability_targets: Some(create_vulnerability_targets(bom_refs)),
+                        ..vulnerability
+                    })
+                    .collect(),
+            ))
+            .filter(|vulnerabilities| !vulnerabilities.0.is_empty()),
+            ..Bom::default()
+        };
+
+        Ok(Self { bom, uv_properties })
+    }
+
+    /// Compare this SBOM against another (e.g., a freshly generated one), reporting the
+    /// components that were added, removed, or changed.
+    ///
+    /// Components are matched by PURL, such that a version change is reported as a removal and an
+    /// addition. Components without a PURL (e.g., local projects) are not compared.
+    pub fn diff(&self, other: &Self) -> SbomDiff {
+        let ours = self.components_by_purl();
+        let theirs = other.components_by_purl();
+
+        let mut diff = SbomDiff::default();
+        for (purl, component) in &ours {
+            match theirs.get(purl) {
+                None => {
+                    diff.removed.insert(purl.clone(), (*component).clone());
+                }
+                Some(other_component) => {
+                    // bom-refs are only unique within a single document, so ignore them.
+                    if !Self::same_component(component, other_component) {
+                        diff.changed.insert(
+                            purl.clone(),
+                            ((*component).clone(), (*other_component).clone()),
+                        );
+                    }
+                }
+            }
+        }
+        for (purl, component) in &theirs {
+            if !ours.contains_key(purl) {
+                diff.added.insert(purl.clone(), (*component).clone());
+            }
+        }
+        diff
+    }
+
+    /// Summarize the contents of the SBOM, e.g., for logging.
+    pub fn summary(&self) -> SbomSummary {
+        let source_type = UvProperty::SourceType.to_string();
+        let mut summary = SbomSummary::default();
+        for component in self
+            .bom
+            .metadata
+            .iter()
+            .filter_map(|metadata| metadata.component.as_ref())
+            .chain(
+                self.bom
+                    .components
+                    .iter()
+                    .flat_map(|components| &components.0),
+            )
+        {
+            summary.components += 1;
+            if component
+                .hashes
+                .as_ref()
+                .is_some_and(|hashes| !hashes.0.is_empty())
+            {
+                summary.hashed += 1;
+            }
+            if let Some(property) = component
+                .properties
+                .iter()
+                .flat_map(|properties| &properties.0)
+                .find(|property| property.name == source_type)
+            {
+                *summary
+                    .source_types
+                    .entry(property.value.to_string())
+                    .or_default() += 1;
+            }
+        }
+        summary.dependencies = self
+            .bom
+            .dependencies
+            .iter()
+            .flat_map(|dependencies| &dependencies.0)
+            .map(|dependency| dependency.dependencies.len())
+            .sum();
+        summary
+    }
+
+    /// Index all components with a PURL, including the main component, by their PURL.
+    fn components_by_purl(&self) -> BTreeMap<String, &Component> {
+        self.bom
+            .metadata
+            .iter()
+            .filter_map(|metadata| metadata.component.as_ref())
+            .chain(
+                self.bom
+                    .components
+                    .iter()
+                    .flat_map(|components| &components.0),
+            )
+            .filter_map(|component| {
+                component
+                    .purl
+                    .as_ref()
+                    .map(|purl| (purl.to_string(), component))
+            })
+            .collect()
+    }
+
+    /// Compare two components, ignoring their bom-refs.
+    fn same_component(left: &Component, right: &Component) -> bool {
+        Component {
+            bom_ref: None,
+            ..left.clone()
+        } == Component {
+            bom_ref: None,
+            ..right.clone()
+        }
+    }
+}
+
+impl From<Bom> for SbomExport {
+    fn from(bom: Bom) -> Self {
+        Self {
+            bom,
+            uv_properties: true,
+        }
+    }
+}
+
+/// The differences between two SBOMs, as computed by [`SbomExport::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SbomDiff {
+    /// Components present only in the newer SBOM, keyed by PURL.
+    pub added: BTreeMap<String, Component>,
+    /// Components present only in the older SBOM, keyed by PURL.
+    pub removed: BTreeMap<String, Component>,
+    /// Components present in both SBOMs with differing contents, keyed by PURL, as
+    /// `(older, newer)` pairs.
+    pub changed: BTreeMap<String, (Component, Component)>,
+}
+
+impl SbomDiff {
+    /// Returns `true` if the SBOMs contain the same components.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A summary of the contents of an SBOM, as computed by [`SbomExport::summary`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SbomSummary {
+    /// The number of components, including the main component.
+    pub components: usize,
+    /// The number of components with at least one hash.
+    pub hashed: usize,
+    /// The number of components of each source type (e.g., `registry` or `git`), as recorded in
+    /// the `uv:package:source_type` property.
+    pub source_types: BTreeMap<String, usize>,
+    /// The number of dependency edges.
+    pub dependencies: usize,
+}
+
+impl SbomSummary {
+    /// The number of components without any hash.
+    pub fn unhashed(&self) -> usize {
+        self.components - self.hashed
+    }
+}
+
+/// An error that occurs when generating, parsing or writing an SBOM.
+///
+/// Errors in the lockfile itself are distinguished from invalid options (e.g., a malformed
+/// BOM-Link), which the user can correct, and from failures to write the document, which may be
+/// transient (e.g., an I/O error).
+#[derive(Debug, thiserror::Error)]
+pub enum SbomError {
+    /// The lockfile couldn't be exported, e.g., as a workspace member has a non-local source.
+    #[error(transparent)]
+    Lock(#[from] LockError),
+    /// A reference to an external SBOM isn't a valid BOM-Link.
+    #[error("Invalid BOM-Link `{link}`: expected `urn:cdx:<serial-number>/<version>`", link = link.cyan())]
+    InvalidBomLink { link: String },
+    /// A qualifier to append to every PURL has a key that PURLs can't carry.
+    #[error("Invalid PURL qualifier key `{key}`: expected lowercase ASCII letters, digits, `.`, `-` or `_`, not starting with a digit", key = key.cyan())]
+    InvalidPurlQualifier { key: String },
+    /// SBOMs generated with and without uv properties were merged.
+    #[error("Failed to merge SBOMs: some were generated with uv properties and some without")]
+    ConflictingUvProperties,
+    /// The SBOM was scoped to a package that isn't a member of the workspace.
+    #[error("`{name}` is not a member of the workspace", name = name.cyan())]
+    UnknownMember { name: PackageName },
+    /// A strict export encountered an issue that would reduce the quality of the SBOM.
+    #[error("Failed to generate a strict SBOM: {warning}")]
+    Strict { warning: SbomWarning },
+    /// A previously generated SBOM couldn't be parsed.
+    #[error("Failed to parse CycloneDX SBOM")]
+    InvalidSbom(#[source] JsonReadError),
+    /// The SBOM couldn't be serialized or written.
+    #[error("Failed to write CycloneDX SBOM")]
+    Serialization(#[from] JsonWriteError),
+}
+
+/// An issue affecting the quality of an SBOM, as reported by [`SbomExportBuilder::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SbomWarning {
+    /// A remote package without an integrity hash (e.g., a Git dependency), which can't be
+    /// verified against the SBOM.
+    UnhashedPackage {
+        name: PackageName,
+        version: Option<Version>,
+    },
+    /// A remote package for which no Package URL (PURL) could be generated, which can't be matched
+    /// against vulnerability databases.
+    MissingPurl {
+        name: PackageName,
+        version: Option<Version>,
+    },
+    /// A remote package without a version (e.g., a Git dependency with a dynamic version), which
+    /// can't be matched against vulnerability databases.
+    MissingVersion { name: PackageName },
+    /// A dependency edge referencing a component that isn't declared in the SBOM, which would
+    /// render the SBOM internally inconsistent. The edge is omitted from the dependency graph.
+    DanglingDependency { bom_ref: String, dependency: String },
+}
+
+impl std::fmt::Display for SbomWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (name, version, issue) = match self {
+            Self::UnhashedPackage { name, version } => (name, version, "has no integrity hash"),
+            Self::MissingPurl { name, version } => (name, version, "has no Package URL"),
+            Self::MissingVersion { name } => (name, &None, "has no version"),
+            Self::DanglingDependency {
+                bom_ref,
+                dependency,
+            } => {
+                return write!(
+                    f,
+                    "`{bom_ref}` depends on `{dependency}`, which isn't a component of the SBOM"
+                );
+            }
+        };
+        if let Some(version) = version {
+            write!(f, "`{name}=={version}` {issue}")
+        } else {
+            write!(f, "`{name}` {issue}")
+        }
+    }
+}
+
+/// Receives progress while an SBOM is generated, e.g., to display a progress bar when exporting a
+/// large lockfile.
+pub trait Reporter {
+    /// Callback to invoke when a component is created for a package.
+    fn on_component(&self, name: &PackageName, version: Option<&Version>);
+
+    /// Callback to invoke when an issue affecting the quality of the SBOM is found.
+    fn on_warning(&self, warning: &SbomWarning);
+}
+
+/// A vulnerability affecting a package, as reported by an external advisory database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VulnerabilityReference {
+    /// The identifier of the vulnerability, e.g., `CVE-2024-3651` or `GHSA-jjg7-2v4v-x38h`.
+    pub id: String,
+    /// The name of the database that published the vulnerability, e.g., `NVD` or `OSV`.
+    pub source: Option<String>,
+    /// A description of the vulnerability.
+    pub description: Option<String>,
+}
+
+/// A lookup of the vulnerabilities affecting a given package version.
+pub type Advisories<'a> = dyn Fn(&PackageName, &Version) -> Vec<VulnerabilityReference> + 'a;
+
+/// A lookup of the URL of the release notes (e.g., a changelog) for a given package version.
+pub type ReleaseNotes<'a> = dyn Fn(&PackageName, &Version) -> Option<String> + 'a;
+
+/// A lookup of the copyright notice (e.g., `Copyright (c) 2008-2020 Andrey Petrov`) for a given
+/// package version.
+pub type Copyrights<'a> = dyn Fn(&PackageName, &Version) -> Option<String> + 'a;
+
+/// A lookup of the license (e.g., the SPDX expression `MIT OR Apache-2.0`) of a given package
+/// version.
+pub type Licenses<'a> = dyn Fn(&PackageName, &Version) -> Option<String> + 'a;
+
+/// A person or team credited with generating the SBOM, e.g., as read from the Git configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SbomAuthor {
+    /// The name of the author.
+    pub name: String,
+    /// The email address of the author.
+    pub email: Option<String>,
+}
+
+impl From<&SbomAuthor> for OrganizationalContact {
+    fn from(author: &SbomAuthor) -> Self {
+        Self {
+            bom_ref: None,
+            name: Some(NormalizedString::new(&author.name)),
+            email: author.email.as_deref().map(NormalizedString::new),
+            phone: None,
+        }
+    }
+}
+
+/// An additional tool credited with generating the SBOM, e.g., a product that embeds uv.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SbomTool {
+    /// The name of the tool.
+    pub name: String,
+    /// The version of the tool.
+    pub version: Option<String>,
+    /// The name of the organization that supplies the tool.
+    pub supplier: Option<String>,
+}
+
+impl From<&SbomTool> for Component {
+    fn from(tool: &SbomTool) -> Self {
+        let mut component = Self::new(
+            Classification::Application,
+            &tool.name,
+            tool.version.as_deref().unwrap_or_default(),
+            None,
+        );
+        component.version = tool.version.as_deref().map(NormalizedString::new);
+        component.supplier = tool
+            .supplier
+            .as_deref()
+            .map(|supplier| OrganizationalEntity {
+                bom_ref: None,
+                name: Some(NormalizedString::new(supplier)),
+                url: None,
+                contact: None,
+            });
+        component
+    }
+}
+
+/// The kind of source from which a package is retrieved, named as in the lockfile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    Registry,
+    Git,
+    Url,
+    Path,
+    Directory,
+    Editable,
+    Virtual,
+}
+
+impl SourceKind {
+    /// Return the name of the source kind, as used in the `uv:package:source_type` property.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Registry => "registry",
+            Self::Git => "git",
+            Self::Url => "url",
+            Self::Path => "path",
+            Self::Directory => "directory",
+            Self::Editable => "editable",
+            Self::Virtual => "virtual",
+        }
+    }
+}
+
+impl From<&Source> for SourceKind {
+    fn from(source: &Source) -> Self {
+        match source {
+            Source::Registry(_) => Self::Registry,
+            Source::Git(_, _) => Self::Git,
+            Source::Direct(_, _) => Self::Url,
+            Source::Path(_) => Self::Path,
+            Source::Directory(_) => Self::Directory,
+            Source::Editable(_) => Self::Editable,
+            Source::Virtual(_) => Self::Virtual,
+        }
+    }
+}
+
+/// When to record the SBOM as having been generated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SbomTimestamp {
+    /// Record the current time.
+    #[default]
+    Now,
+    /// Record the given time, e.g., derived from `SOURCE_DATE_EPOCH` for reproducible builds.
+    Fixed(jiff::Timestamp),
+    /// Omit the timestamp, such that the SBOM doesn't depend on the time of generation.
+    Omit,
+}
+
+impl SbomTimestamp {
+    /// Create the `metadata.timestamp` of the SBOM, if any.
+    ///
+    /// The current time is formatted like a fixed time, such that the timestamps of all SBOMs are
+    /// consistent.
+    fn to_date_time(self) -> Option<cyclonedx_bom::prelude::DateTime> {
+        let timestamp = match self {
+            Self::Now => jiff::Timestamp::now(),
+            Self::Fixed(timestamp) => timestamp,
+            Self::Omit => return None,
+        };
+        cyclonedx_bom::prelude::DateTime::try_from(timestamp.to_string()).ok()
+    }
+}
+
+/// How to export packages without an integrity hash (e.g., local or Git dependencies), which can't
+/// be verified.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UnhashedPackages {
+    /// Include unhashed packages like any other package.
+    #[default]
+    Include,
+    /// Include unhashed packages, marked with the `uv:package:is_unhashed` property.
+    Mark,
+    /// Omit unhashed packages, other than the roots, from the export.
+    Exclude,
+}
+
+/// An export target over a parsed [`Lock`], for tools that read a lockfile directly rather than
+/// discovering a project.
+///
+/// By default, the target is rooted at the workspace root package or, for a virtual workspace, at
+/// every workspace member.
+#[derive(Debug, Clone)]
+pub struct LockTarget<'lock> {
+    lock: &'lock Lock,
+    install_path: &'lock Path,
+    roots: Vec<PackageName>,
+}
+
+impl<'lock> LockTarget<'lock> {
+    /// Create a target for the given lockfile, located in the workspace at `install_path`.
+    pub fn new(lock: &'lock Lock, install_path: &'lock Path) -> Self {
+        let roots = match lock.root() {
+            Some(root) => vec![root.name().clone()],
+            None => lock.members().iter().cloned().collect(),
+        };
+        Self {
+            lock,
+            install_path,
+            roots,
+        }
+    }
+
+    /// Root the target at the given packages instead, e.g., a single workspace member.
+    ///
+    /// A single root is treated as the target's project (e.g., by `no_install_project`).
+    #[must_use]
+    pub fn with_roots(mut self, roots: Vec<PackageName>) -> Self {
+        self.roots = roots;
+        self
+    }
+}
+
+impl<'lock> Installable<'lock> for LockTarget<'lock> {
+    fn install_path(&self) -> &'lock Path {
+        self.install_path
+    }
+
+    fn lock(&self) -> &'lock Lock {
+        self.lock
+    }
+
+    fn roots(&self) -> impl Iterator<Item = &PackageName> {
+        self.roots.iter()
+    }
+
+    fn project_name(&self) -> Option<&PackageName> {
+        match self.roots.as_slice() {
+            [root] => Some(root),
+            [] => self.lock.root().map(Package::name),
+            _ => None,
+        }
+    }
+}
+
+/// Builds an [`SbomExport`] from a lockfile.
+///
+/// By default, the export covers the full dependency closure of the target, with the root package
+/// declared as the main component.
+///
+/// Generation is synchronous and CPU-bound, scaling with the size of the lockfile. To generate an
+/// SBOM from an async context without stalling the executor, move an owned [`Lock`] into a
+/// blocking task (e.g., with `tokio::task::spawn_blocking`) and configure the builder there: the
+/// builder only borrows its inputs (which need not be `Send`, as with a [`Reporter`]), while the
+/// generated [`SbomExport`] is owned and `Send`, such that it can be returned to the async context.
+pub struct SbomExportBuilder<'a, Target> {
+    target: &'a Target,
+    prune: &'a [PackageName],
+    prune_patterns: Option<&'a GlobSet>,
+    extras: ExtrasSpecificationWithDefaults,
+    groups: DependencyGroupsWithDefaults,
+    annotate: bool,
+    editable: Option<EditableMode>,
+    install_options: InstallOptions,
+    preview: Preview,
+    all_packages: bool,
+    top_level: bool,
+    workspace_packages: bool,
+    swid: bool,
+    hashes: bool,
+    direct_only: bool,
+    main_component: Option<(&'a str, &'a str)>,
+    main_component_version: Option<&'a str>,
+    advisories: Option<&'a Advisories<'a>>,
+    vulnerable_only: bool,
+    release_notes: Option<&'a ReleaseNotes<'a>>,
+    copyrights: Option<&'a Copyrights<'a>>,
+    unhashed: UnhashedPackages,
+    marker_environment: Option<&'a MarkerEnvironment>,
+    authors: &'a [SbomAuthor],
+    tools: &'a [SbomTool],
+    uv_properties: bool,
+    bom_links: &'a [(PackageName, String)],
+    requires_python: bool,
+    topological: bool,
+    dependency_graph: bool,
+    tool_version: bool,
+    tags: Option<&'a Tags>,
+    download_urls: bool,
+    upload_times: bool,
+    categories: bool,
+    distribution_components: bool,
+    purl_qualifiers: Option<&'a BTreeMap<String, String>>,
+    build_info: bool,
+    install_option_properties: bool,
+    source_kinds: Option<&'a [SourceKind]>,
+    reporter: Option<&'a dyn Reporter>,
+    member: Option<&'a PackageName>,
+    timestamp: SbomTimestamp,
+    strict: bool,
+}
+
+impl<'a, Target> SbomExportBuilder<'a, Target> {
+    pub fn new(target: &'a Target) -> Self {
+        Self {
+            target,
+            prune: &[],
+            prune_patterns: None,
+            extras: ExtrasSpecificationWithDefaults::none(),
+            groups: DependencyGroupsWithDefaults::none(),
+            annotate: false,
+            editable: None,
+            install_options: InstallOptions::default(),
+            preview: Preview::default(),
+            all_packages: false,
+            top_level: true,
+            workspace_packages: true,
+            swid: false,
+            hashes: false,
+            direct_only: false,
+            main_component: None,
+            main_component_version: None,
+            advisories: None,
+            vulnerable_only: false,
+            release_notes: None,
+            copyrights: None,
+            unhashed: UnhashedPackages::Include,
+            marker_environment: None,
+            authors: &[],
+            tools: &[],
+            uv_properties: true,
+            bom_links: &[],
+            requires_python: false,
+            topological: false,
+            dependency_graph: true,
+            tool_version: true,
+            tags: None,
+            download_urls: false,
+            upload_times: false,
+            categories: false,
+            distribution_components: false,
+            purl_qualifiers: None,
+            build_info: false,
+            install_option_properties: false,
+            source_kinds: None,
+            reporter: None,
+            member: None,
+            timestamp: SbomTimestamp::Now,
+            strict: false,
+        }
+    }
+
+    /// Exclude the given packages, and any dependencies only required through them.
+    #[must_use]
+    pub fn with_prune(mut self, prune: &'a [PackageName]) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Exclude the packages whose names match any of the given patterns (e.g., `types-*`), and any
+    /// dependencies only required through them.
+    ///
+    /// Patterns are matched against normalized package names, in addition to the packages passed
+    /// to [`SbomExportBuilder::with_prune`].
+    #[must_use]
+    pub fn with_prune_patterns(mut self, prune_patterns: &'a GlobSet) -> Self {
+        self.prune_patterns = Some(prune_patterns);
+        self
+    }
+
+    /// Set the extras to include.
+    #[must_use]
+    pub fn with_extras(mut self, extras: &ExtrasSpecificationWithDefaults) -> Self {
+        self.extras = extras.clone();
+        self
+    }
+
+    /// Set the dependency groups to include.
+    #[must_use]
+    pub fn with_groups(mut self, groups: &DependencyGroupsWithDefaults) -> Self {
+        self.groups = groups.clone();
+        self
+    }
+
+    /// Describe each component by the packages that depend on it (e.g., `via requests`).
+    #[must_use]
+    pub fn with_annotate(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+        self
+    }
+
+    /// Set how editable packages are exported.
+    #[must_use]
+    pub fn with_editable(mut self, editable: Option<EditableMode>) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Set the options controlling which packages are included, e.g., `--no-emit-project`.
+    #[must_use]
+    pub fn with_install_options(mut self, install_options: &InstallOptions) -> Self {
+        self.install_options = install_options.clone();
+        self
+    }
+
+    /// Set the preview settings, which determine whether to warn that the export is experimental.
+    #[must_use]
+    pub fn with_preview(mut self, preview: Preview) -> Self {
+        self.preview = preview;
+        self
+    }
+
+    /// Export all workspace members, anchored to a synthetic root component.
+    #[must_use]
+    pub fn with_all_packages(mut self, all_packages: bool) -> Self {
+        self.all_packages = all_packages;
+        self
+    }
+
+    /// Declare the root package in `metadata.component`, rather than listing it as a regular
+    /// component.
+    #[must_use]
+    pub fn with_top_level(mut self, top_level: bool) -> Self {
+        self.top_level = top_level;
+        self
+    }
+
+    /// List the root package and the workspace members as components.
+    ///
+    /// When disabled, only the third-party footprint of the workspace is listed: the main component
+    /// remains the subject of the SBOM, but depends directly on the packages that the workspace
+    /// requires, in place of the workspace members through which they're required.
+    #[must_use]
+    pub fn with_workspace_packages(mut self, workspace_packages: bool) -> Self {
+        self.workspace_packages = workspace_packages;
+        self
+    }
+
+    /// Generate a SWID tag for each component.
+    #[must_use]
+    pub fn with_swid(mut self, swid: bool) -> Self {
+        self.swid = swid;
+        self
+    }
+
+    /// Include the hashes of each component's distributions, and reference each remote
+    /// distribution by its download URL and hash.
+    ///
+    /// A component lists every hash recorded in the lockfile verbatim, whatever the algorithm, so it
+    /// may carry several hashes of the same algorithm (e.g., one `sha256` for the source
+    /// distribution and one per wheel, along with any zstd-compressed wheel). The hash of each
+    /// source distribution and wheel is also recorded in a `uv:artifact:<filename>` property, which
+    /// identifies the distribution it belongs to. Without this option, no hashes are included.
+    #[must_use]
+    pub fn with_hashes(mut self, hashes: bool) -> Self {
+        self.hashes = hashes;
+        self
+    }
+
+    /// Limit the export to the roots and their direct dependencies.
+    #[must_use]
+    pub fn with_direct_only(mut self, direct_only: bool) -> Self {
+        self.direct_only = direct_only;
+        self
+    }
+
+    /// Limit the export to the packages retrieved from the given kinds of source (e.g., only
+    /// packages from a registry), omitting all other packages other than the roots.
+    #[must_use]
+    pub fn with_source_kinds(mut self, source_kinds: &'a [SourceKind]) -> Self {
+        self.source_kinds = Some(source_kinds);
+        self
+    }
+
+    /// Mark or omit the packages without an integrity hash.
+    #[must_use]
+    pub fn with_unhashed(mut self, unhashed: UnhashedPackages) -> Self {
+        self.unhashed = unhashed;
+        self
+    }
+
+    /// Override the name and version of the main component, e.g., to stamp the published version
+    /// of a release.
+    ///
+    /// By default, the main component is named after the root package (or the workspace).
+    #[must_use]
+    pub fn with_main_component(mut self, name: &'a str, version: &'a str) -> Self {
+        self.main_component = Some((name, version));
+        self
+    }
+
+    /// Override the version of the main component, e.g., with the version derived from the Git tag
+    /// of a release (as in `git describe`), leaving its name unchanged.
+    ///
+    /// The main component of a virtual workspace has no version otherwise.
+    /// [`SbomExportBuilder::with_main_component`] takes precedence.
+    #[must_use]
+    pub fn with_main_component_version(mut self, version: &'a str) -> Self {
+        self.main_component_version = Some(version);
+        self
+    }
+
+    /// Attach the vulnerabilities reported by the given advisory lookup, which is queried for each
+    /// exported package version.
+    ///
+    /// uv doesn't ship an advisory database; this allows callers to produce a single document
+    /// enriched with their own vulnerability records.
+    #[must_use]
+    pub fn with_advisories(mut self, advisories: &'a Advisories<'a>) -> Self {
+        self.advisories = Some(advisories);
+        self
+    }
+
+    /// Limit the export to the packages affected by a vulnerability, as reported by
+    /// [`SbomExportBuilder::with_advisories`], along with every package on a dependency path from
+    /// the roots to them, such that it's visible how each affected package is pulled in.
+    ///
+    /// Has no effect without advisories.
+    #[must_use]
+    pub fn with_vulnerable_only(mut self, vulnerable_only: bool) -> Self {
+        self.vulnerable_only = vulnerable_only;
+        self
+    }
+
+    /// Reference the release notes of each exported package version, as reported by the given
+    /// lookup.
+    ///
+    /// The lockfile doesn't record the project URLs of each package; this allows callers that
+    /// retrieve them (e.g., from the package metadata) to surface changelogs to reviewers.
+    #[must_use]
+    pub fn with_release_notes(mut self, release_notes: &'a ReleaseNotes<'a>) -> Self {
+        self.release_notes = Some(release_notes);
+        self
+    }
+
+    /// Populate the copyright of each exported package version, as reported by the given lookup.
+    ///
+    /// The lockfile doesn't record the authors or copyright notices of each package; this allows
+    /// callers that retrieve them (e.g., from the package metadata) to produce an SBOM suitable
+    /// for license compliance. Packages without a copyright notice omit the field.
+    #[must_use]
+    pub fn with_copyrights(mut self, copyrights: &'a Copyrights<'a>) -> Self {
+        self.copyrights = Some(copyrights);
+        self
+    }
+
+    /// Annotate components with the `uv:`-namespaced properties (e.g., `uv:package:source_type`).
+    ///
+    /// Enabled by default. Some validators reject unknown properties; disabling them produces a
+    /// document that only uses standard CycloneDX fields.
+    #[must_use]
+    pub fn with_uv_properties(mut self, uv_properties: bool) -> Self {
+        self.uv_properties = uv_properties;
+        self
+    }
+
+    /// Reference the given packages by the BOM-Link (`urn:cdx:<serial-number>/<version>`) of an
+    /// external BOM that describes them, e.g., the SBOM published by another repository.
+    ///
+    /// The dependencies that are only required through the linked packages are described by the
+    /// external BOM, and so are omitted from the export.
+    #[must_use]
+    pub fn with_bom_links(mut self, bom_links: &'a [(PackageName, String)]) -> Self {
+        self.bom_links = bom_links;
+        self
+    }
+
+    /// Record the range of Python versions supported by the lockfile (e.g., `>=3.9`) as a
+    /// `uv:lock:requires_python` property on the SBOM metadata, since the SBOM only describes
+    /// environments within that range.
+    #[must_use]
+    pub fn with_requires_python(mut self, requires_python: bool) -> Self {
+        self.requires_python = requires_python;
+        self
+    }
+
+    /// Describe how the SBOM was generated in the SBOM metadata, such that it can be linked to a
+    /// build attestation (e.g., SLSA provenance), with the following properties:
+    ///
+    /// - `uv:build:version`: The version of uv.
+    /// - `uv:build:os` and `uv:build:arch`: The operating system and architecture on which uv ran
+    ///   (e.g., `linux` and `x86_64`).
+    /// - `uv:build:resolution`: `platform` if the SBOM is limited to a single environment (see
+    ///   [`SbomExportBuilder::with_marker_environment`]), or `universal` otherwise.
+    #[must_use]
+    pub fn with_build_info(mut self, build_info: bool) -> Self {
+        self.build_info = build_info;
+        self
+    }
+
+    /// Record the install options that shaped the set of exported packages (see
+    /// [`SbomExportBuilder::with_install_options`]) in the SBOM metadata, such that the SBOM can be
+    /// regenerated with the same options.
+    ///
+    /// Each active option is recorded as a `uv:install:<option>` property (e.g.,
+    /// `uv:install:no_project`), with the packages of `no_package` and `only_package` separated by
+    /// commas.
+    #[must_use]
+    pub fn with_install_option_properties(mut self, install_option_properties: bool) -> Self {
+        self.install_option_properties = install_option_properties;
+        self
+    }
+
+    /// Declare each component after the components it depends on, rather than ordering the
+    /// components by package, for importers that expect dependencies to be declared first.
+    ///
+    /// Components in a dependency cycle are declared in order of their bom-ref.
+    #[must_use]
+    pub fn with_topological_order(mut self, topological: bool) -> Self {
+        self.topological = topological;
+        self
+    }
+
+    /// Record the relationships between components in the `dependencies` section.
+    ///
+    /// Enabled by default. Disabling it produces a flat inventory of the components, omitting the
+    /// `dependencies` section along with the `compositions` describing its completeness.
+    #[must_use]
+    pub fn with_dependency_graph(mut self, dependency_graph: bool) -> Self {
+        self.dependency_graph = dependency_graph;
+        self
+    }
+
+    /// Record the version of uv in `metadata.tools`.
+    ///
+    /// Enabled by default. Disabling it avoids disclosing the exact build tooling in SBOMs that are
+    /// shared externally, while uv is still identified as the generator.
+    #[must_use]
+    pub fn with_tool_version(mut self, tool_version: bool) -> Self {
+        self.tool_version = tool_version;
+        self
+    }
+
+    /// Record the tags of the wheel that would be installed for each registry package on the
+    /// platform with the given tags (e.g., `cp312-cp312-manylinux_2_17_x86_64`), which determine
+    /// its ABI and platform compatibility.
+    ///
+    /// Packages for which no compatible wheel is locked (i.e., that would be built from source) are
+    /// left without tags.
+    #[must_use]
+    pub fn with_tags(mut self, tags: &'a Tags) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Record the URL of the source distribution of each registry package as a
+    /// `uv:package:download_url` property, such that a mirror can be populated from the SBOM without
+    /// resolving against the index.
+    #[must_use]
+    pub fn with_download_urls(mut self, download_urls: bool) -> Self {
+        self.download_urls = download_urls;
+        self
+    }
+
+    /// Record when the release of each registry package was uploaded to the index as a
+    /// `uv:package:upload_time` property, for auditing the freshness of dependencies.
+    ///
+    /// Packages for which the index didn't report an upload time are left without one.
+    #[must_use]
+    pub fn with_upload_times(mut self, upload_times: bool) -> Self {
+        self.upload_times = upload_times;
+        self
+    }
+
+    /// Record whether each package is required at runtime (`runtime`), or only through dependency
+    /// groups (`dev`), as a `uv:package:category` property, such that policies can distinguish
+    /// development tools from shipped code.
+    ///
+    /// Build-system requirements aren't locked, and so are never included in the SBOM.
+    #[must_use]
+    pub fn with_categories(mut self, categories: bool) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Declare each distribution of a registry package (i.e., its source distribution and each of
+    /// its wheels) as a `file` component nested within the package's component, carrying its own
+    /// filename, hash and download URL.
+    ///
+    /// This distinguishes the logical package from the artifacts that may be installed for it, at
+    /// the cost of a substantially larger SBOM.
+    #[must_use]
+    pub fn with_distribution_components(mut self, distribution_components: bool) -> Self {
+        self.distribution_components = distribution_components;
+        self
+    }
+
+    /// Append the given qualifiers to the PURL of every package (e.g., an organization's internal
+    /// `repository_url`), in their canonical, percent-encoded form.
+    ///
+    /// Qualifiers derived from a package's source (e.g., the `vcs_url` of a Git dependency) take
+    /// precedence, and local packages, which have no PURL, are unaffected. Generation fails if a
+    /// key isn't a valid qualifier key (e.g., if it contains uppercase letters or `=`).
+    #[must_use]
+    pub fn with_purl_qualifiers(mut self, purl_qualifiers: &'a BTreeMap<String, String>) -> Self {
+        self.purl_qualifiers = Some(purl_qualifiers);
+        self
+    }
+
+    /// Set when the SBOM is recorded as having been generated, which defaults to the current time.
+    ///
+    /// Unless the current time is recorded, the serial number is derived from the contents of the
+    /// SBOM (as a version 5 UUID) rather than generated at random, such that regenerating the SBOM
+    /// yields an identical document.
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: SbomTimestamp) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Record the given authors in `metadata.authors`, which is omitted if there are none.
+    #[must_use]
+    pub fn with_authors(mut self, authors: &'a [SbomAuthor]) -> Self {
+        self.authors = authors;
+        self
+    }
+
+    /// Record the given tools in `metadata.tools` after uv, e.g., to credit a product that embeds
+    /// uv, while uv is still recorded as the generator.
+    #[must_use]
+    pub fn with_tools(mut self, tools: &'a [SbomTool]) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Limit the export to the packages that would be installed in the given environment.
+    ///
+    /// By default, the export is universal, covering the packages for every supported platform.
+    #[must_use]
+    pub fn with_marker_environment(mut self, marker_environment: &'a MarkerEnvironment) -> Self {
+        self.marker_environment = Some(marker_environment);
+        self
+    }
+
+    /// Limit the export to the given workspace member and the packages it requires, declaring the
+    /// member as the main component, e.g., to generate the SBOM of a single service in a workspace.
+    ///
+    /// Takes precedence over [`SbomExportBuilder::with_all_packages`].
+    #[must_use]
+    pub fn with_member(mut self, member: &'a PackageName) -> Self {
+        self.member = Some(member);
+        self
+    }
+
+    /// Report each component as it's created, along with any issues affecting the quality of the
+    /// SBOM.
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: &'a dyn Reporter) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// Fail if any issue would reduce the quality of the SBOM (e.g., a remote package without a
+    /// hash or version), rather than generating a degraded SBOM.
+    ///
+    /// By default, such issues are only reported (see [`SbomExportBuilder::validate`]).
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Generate the SBOM.
+    pub fn build<'lock>(&self) -> Result<SbomExport, SbomError>
+    where
+        Target: Installable<'lock>,
+    {
+        self.build_with_warnings().map(|(export, _)| export)
+    }
+
+    /// Report the issues that would reduce the quality of the SBOM (e.g., packages that can't be
+    /// verified), without generating it, such that CI can be gated on them.
+    pub fn validate<'lock>(&self) -> Result<Vec<SbomWarning>, SbomError>
+    where
+        Target: Installable<'lock>,
+    {
+        self.build_with_warnings().map(|(_, warnings)| warnings)
+    }
+
+    fn build_with_warnings<'lock>(&self) -> Result<(SbomExport, Vec<SbomWarning>), SbomError>
+    where
+        Target: Installable<'lock>,
+    {
+        // Scope the export to a single member by rooting the target at it.
+        if let Some(member) = self.member {
+            let lock = self.target.lock();
+            if !lock.members().contains(member)
+                && lock.root().is_none_or(|root| root.name() != member)
+            {
+                return Err(SbomError::UnknownMember {
+                    name: member.clone(),
+                });
+            }
+            let target =
+                LockTarget::new(lock, self.target.install_path()).with_roots(vec![member.clone()]);
+            return self.generate(&target);
+        }
+        self.generate(self.target)
+    }
+
+    fn generate<'lock>(
+        &self,
+        target: &impl Installable<'lock>,
+    ) -> Result<(SbomExport, Vec<SbomWarning>), SbomError> {
+        let Self {
+            target: _,
+            prune,
+            prune_patterns,
+            ref extras,
+            ref groups,
+            annotate,
+            editable,
+            ref install_options,
+            preview,
+            all_packages,
+            top_level,
+            workspace_packages,
+            swid,
+            hashes,
+            direct_only,
+            main_component,
+            main_component_version,
+            advisories,
+            vulnerable_only,
+            release_notes,
+            copyrights,
+            unhashed,
+            marker_environment,
+            authors,
+            tools,
+            uv_properties,
+            bom_links,
+            requires_python,
+            topological,
+            dependency_graph,
+            tool_version,
+            tags,
+            download_urls,
+            upload_times,
+            categories,
+            distribution_components,
+            purl_qualifiers,
+            build_info,
+            install_option_properties,
+            source_kinds,
+            reporter,
+            member,
+            timestamp,
+            strict,
+        } = *self;
+
+        // A single member is never anchored to a synthetic root.
+        let all_packages = all_packages && member.is_none();
+
+        // Expand the prune patterns into the matching package names, such that pruning by pattern
+        // behaves exactly as pruning each match by name.
+        let prune = if let Some(prune_patterns) = prune_patterns {
+            prune
+                .iter()
+                .chain(
+                    target
+                        .lock()
+                        .packages()
+                        .iter()
+                        .map(|package| &package.id.name)
+                        .filter(|name| prune_patterns.is_match(name.as_str())),
+                )
+                .unique()
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            prune.to_vec()
+        };
+        let prune = prune.as_slice();
+
+        let bom_links = bom_links
+            .iter()
+            .map(|(name, link)| {
+                let reference = ExternalReference::new(
+                    ExternalReferenceType::Bom,
+                    Uri::BomLink(BomLink(link.clone())),
+                );
+                if reference.validate_version(SpecVersion::V1_5).has_errors() {
+                    return Err(SbomError::InvalidBomLink { link: link.clone() });
+                }
+                Ok((name, reference))
+            })
+            .collect::<Result<FxHashMap<_, _>, SbomError>>()?;
+
+        if let Some(key) = purl_qualifiers
+            .into_iter()
+            .flat_map(BTreeMap::keys)
+            .find(|key| !ComponentBuilder::is_valid_qualifier_key(key))
+        {
+            return Err(SbomError::InvalidPurlQualifier { key: key.clone() });
+        }
+
+        if !preview.is_enabled(PreviewFeature::SbomExport) {
+            warn_user!(
+                "`uv export --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                PreviewFeature::SbomExport
+            );
+        }
+
+        // Extract the packages from the lock file.
+        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+            target,
+            prune,
+            extras,
+            groups,
+            annotate,
+            install_options,
+        )?;
+
+        // Resolve the reachability markers against the target environment. This happens before any
+        // other filtering, so that the packages of other platforms aren't reported as excluded.
+        if let Some(marker_environment) = marker_environment {
+            nodes.retain(|node| {
+                target.roots().contains(&node.package.id.name)
+                    || node.marker.evaluate(marker_environment, &[])
+            });
+        }
+
+        nodes.sort_unstable_by_key(|node| &node.package.id);
+
+        let direct_dependencies = direct_dependency_ids(target, extras, groups);
+        let exported_ids = nodes
+            .iter()
+            .map(|node| &node.package.id)
+            .collect::<FxHashSet<_>>();
+
+        // Limit the export to the roots and their direct dependencies. As only the retained packages
+        // are registered as components, edges to transitive dependencies are dropped from the graph.
+        if direct_only {
+            nodes.retain(|node| {
+                target.roots().contains(&node.package.id.name)
+                    || direct_dependencies.contains(&node.package.id)
+            });
+        }
+
+        if unhashed == UnhashedPackages::Exclude {
+            nodes.retain(|node| {
+                target.roots().contains(&node.package.id.name) || !node.package.hashes().is_empty()
+            });
+        }
+
+        if let Some(source_kinds) = source_kinds {
+            nodes.retain(|node| {
+                target.roots().contains(&node.package.id.name)
+                    || source_kinds.contains(&SourceKind::from(&node.package.id.source))
+            });
+        }
+
+        // Linked packages are described by their external BOM, so omit the dependencies that are
+        // only reachable through them.
+        if !bom_links.is_empty() {
+            let packages = nodes
+                .iter()
+                .map(|node| (&node.package.id, node.package))
+                .collect::<FxHashMap<_, _>>();
+            let mut queue = nodes
+                .iter()
+                .filter(|node| {
+                    target.roots().contains(&node.package.id.name)
+                        || direct_dependencies.contains(&node.package.id)
+                })
+                .map(|node| node.package)
+                .collect::<VecDeque<_>>();
+            let mut reachable = FxHashSet::default();
+            while let Some(package) = queue.pop_front() {
+                if !reachable.insert(&package.id) {
+                    continue;
+                }
+                if bom_links.contains_key(&package.id.name)
+                    && !target.roots().contains(&package.id.name)
+                {
+                    continue;
+                }
+                let dependencies = package
+                    .dependencies
+                    .iter()
+                    .chain(package.optional_dependencies.values().flatten())
+                    .chain(package.dependency_groups.values().flatten());
+                queue.extend(
+                    dependencies.filter_map(|dependency| packages.get(&dependency.package_id)),
+                );
+            }
+            nodes.retain(|node| reachable.contains(&node.package.id));
+        }
+
+        // Query the advisories once for each package version, such that the vulnerabilities used to
+        // filter the export are exactly those reported in it.
+        let advisories = advisories.map(|advisories| {
+            nodes
+                .iter()
+                .filter_map(|node| {
+                    let version = node.package.id.version.as_ref()?;
+                    let references = advisories(&node.package.id.name, version);
+                    (!references.is_empty()).then_some((&node.package.id, references))
+                })
+                .collect::<FxHashMap<_, _>>()
+        });
+
+        // Retain the affected packages and, walking the graph in reverse, the packages through
+        // which they're reachable.
+        if vulnerable_only && let Some(advisories) = &advisories {
+            let mut dependents: FxHashMap<&PackageId, Vec<&PackageId>> = FxHashMap::default();
+            for node in &nodes {
+                let dependencies = node
+                    .package
+                    .dependencies
+                    .iter()
+                    .chain(node.package.optional_dependencies.values().flatten())
+                    .chain(node.package.dependency_groups.values().flatten());
+                for dependency in dependencies {
+                    dependents
+                        .entry(&dependency.package_id)
+                        .or_default()
+                        .push(&node.package.id);
+                }
+            }
+            let mut queue = advisories.keys().copied().collect::<VecDeque<_>>();
+            let mut affected = FxHashSet::default();
+            while let Some(id) = queue.pop_front() {
+                if !affected.insert(id) {
+                    continue;
+                }
+                queue.extend(dependents.get(id).into_iter().flatten().copied());
+            }
+            nodes.retain(|node| {
+                target.roots().contains(&node.package.id.name)
+                    || affected.contains(&node.package.id)
+            });
+        }
+
+        // CycloneDX requires exactly one root component in `metadata.component`.
+        let root = match target.roots().collect::<Vec<_>>().as_slice() {
+            // Single root: use it directly, even if it was excluded from the export
+            [single_root] => nodes
+                .iter()
+                .find(|node| &node.package.id.name == *single_root)
+                .map(|node| node.package)
+                .or_else(|| target.lock().find_by_name(single_root).ok().flatten()),
+            // Multiple roots or no roots: use fallback
+            _ => None,
+        }
+        .or_else(|| target.lock().root()); // Fallback to project root
+
+        let mut component_builder = ComponentBuilder {
+            editable,
+            overrides: target
+                .lock()
+                .manifest
+                .overrides
+                .iter()
+                .into_group_map_by(|requirement| &requirement.name)
+                .into_iter()
+                .map(|(name, requirements)| {
+                    (
+                        name,
+                        format!("Overridden by: {}", requirements.iter().join(", ")),
+                    )
+                })
+                .collect(),
+            requested_specifiers: {
+                let requested_specifiers = requested_specifiers(target, groups);
+                direct_dependencies
+                    .iter()
+                    .filter_map(|id| Some((*id, requested_specifiers.get(&id.name)?.clone())))
+                    .collect()
+            },
+            direct_dependencies: direct_dependencies.clone(),
+            swid,
+            hashes,
+            mark_unhashed: unhashed == UnhashedPackages::Mark,
+            bom_links,
+            release_notes: release_notes
+                .map(|release_notes| {
+                    nodes
+                        .iter()
+                        .filter_map(|node| {
+                            let version = node.package.id.version.as_ref()?;
+                            let url = release_notes(&node.package.id.name, version)?;
+                            Some((
+                                &node.package.id,
+                                ExternalReference::new(
+                                    ExternalReferenceType::ReleaseNotes,
+                                    Url::new(&url),
+                                ),
+                            ))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            copyrights: copyrights
+                .map(|copyrights| {
+                    nodes
+                        .iter()
+                        .filter_map(|node| {
+                            let version = node.package.id.version.as_ref()?;
+                            let copyright = copyrights(&node.package.id.name, version)?;
+                            Some((&node.package.id, copyright))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            tags,
+            download_urls,
+            upload_times,
+            runtime_packages: categories
+                .then(|| runtime_package_ids(target, prune, extras, groups)),
+            distribution_components,
+            purl_qualifiers,
+            ..ComponentBuilder::default()
+        };
+
+        let report_component = |package: &Package| {
+            if let Some(reporter) = reporter {
+                reporter.on_component(&package.id.name, package.id.version.as_ref());
+            }
+        };
+
+        let mut metadata = Metadata {
+            // Unless disabled (e.g., for a library, where the consuming application is the real
+            // root), the root package is the subject of the SBOM and is declared in
+            // `metadata.component`.
+            component: root.filter(|_| top_level).map(|package| {
+                report_component(package);
+                component_builder.create_component(package, PackageType::Root, None, None, &[])
+            }),
+            timestamp: timestamp.to_date_time(),
+            tools: Some(create_tools(tool_version, tools)),
+            authors: (!authors.is_empty())
+                .then(|| authors.iter().map(OrganizationalContact::from).collect()),
+            lifecycles: Some(create_lifecycles()),
+            properties: None,
+            ..Metadata::default()
+        };
+
+        let mut metadata_properties = Vec::new();
+        if requires_python {
+            metadata_properties.push(
+                UvProperty::RequiresPython.with_value(&target.lock().requires_python().to_string()),
+            );
+        }
+        if let Some(exclude_newer) = target.lock().exclude_newer().global {
+            metadata_properties
+                .push(UvProperty::ExcludeNewer.with_value(&exclude_newer.timestamp().to_string()));
+        }
+        if install_option_properties {
+            metadata_properties.extend(create_install_option_properties(install_options));
+        }
+        if build_info {
+            metadata_properties.extend([
+                UvProperty::BuildVersion.with_value(uv_version::version()),
+                UvProperty::BuildOs.with_value(std::env::consts::OS),
+                UvProperty::BuildArch.with_value(std::env::consts::ARCH),
+                UvProperty::BuildResolution.with_value(if marker_environment.is_some() {
+                    "platform"
+                } else {
+                    "universal"
+                }),
+            ]);
+        }
+        if uv_properties && !metadata_properties.is_empty() {
+            metadata.properties = Some(Properties(metadata_properties));
+        }
+
+        let groups_by_package = groups_by_package(target, prune, groups);
+
+        let workspace_member_ids = nodes
+            .iter()
+            .filter_map(|node| {
+                if target.lock().members().contains(&node.package.id.name) {
+                    Some(&node.package.id)
+                } else {
+                    None
+                }
+            })
+            .collect::<FxHashSet<_>>();
+
+        let mut components = nodes
+            .iter()
+            .filter(|node| !top_level || root.is_none_or(|root_pkg| root_pkg.id != node.package.id)) // Filter out root package as this is included in `metadata`
+            .map(|node| {
+                let package_type = if root.is_some_and(|root_pkg| root_pkg.id == node.package.id) {
+                    PackageType::Root
+                } else if workspace_member_ids.contains(&node.package.id) {
+                    let path = match &node.package.id.source {
+                        Source::Path(path)
+                        | Source::Directory(path)
+                        | Source::Editable(path)
+                        | Source::Virtual(path) => path,
+                        Source::Registry(_) | Source::Git(_, _) | Source::Direct(_, _) => {
+                            // Workspace packages should always be local dependencies
+                            return Err(LockErrorKind::NonLocalWorkspaceMember {
+                                id: node.package.id.clone(),
+                            }
+                            .into());
+                        }
+                    };
+                    PackageType::Workspace(path)
+                } else {
+                    PackageType::Dependency
+                };
+                report_component(node.package);
+                Ok(component_builder.create_component(
+                    node.package,
+                    package_type,
+                    Some(&node.marker),
+                    groups_by_package.get(&node.package.id),
+                    &node.dependents,
+                ))
+            })
+            .collect::<Result<Vec<_>, LockError>>()?;
+
+        let mut dependencies = create_dependencies(&nodes, &component_builder, marker_environment);
+        let vulnerabilities = advisories
+            .as_ref()
+            .map(|advisories| create_vulnerabilities(&nodes, &component_builder, advisories))
+            .filter(|vulnerabilities| !vulnerabilities.is_empty());
+
+        // Identify the components whose dependencies were deliberately excluded from the export, as
+        // opposed to those that are unreachable (e.g., due to conflicts).
+        let retained_ids = nodes
+            .iter()
+            .map(|node| &node.package.id)
+            .collect::<FxHashSet<_>>();
+        let is_excluded = |id: &PackageId| {
+            !retained_ids.contains(id)
+                && (prune.contains(&id.name)
+                    || exported_ids.contains(id)
+                    || !install_options.include_package(
+                        target.lock().find_by_id(id).as_install_target(),
+                        target.project_name(),
+                        target.lock().members(),
+                    ))
+        };
+        let mut excluded_first_party = false;
+        let mut excluded_third_party = false;
+        let mut incomplete_bom_refs = BTreeSet::new();
+        // The extras enabled for each package, i.e., those requested for the roots, or by an edge
+        // to the package.
+        let mut enabled_extras: FxHashMap<&PackageId, FxHashSet<&ExtraName>> = FxHashMap::default();
+        for node in &nodes {
+            if target.roots().contains(&node.package.id.name) {
+                enabled_extras
+                    .entry(&node.package.id)
+                    .or_default()
+                    .extend(extras.extra_names(node.package.optional_dependencies.keys()));
+            }
+            let dependencies = node
+                .package
+                .dependencies
+                .iter()
+                .chain(node.package.optional_dependencies.values().flatten())
+                .chain(node.package.dependency_groups.values().flatten());
+            for dependency in dependencies {
+                enabled_extras
+                    .entry(&dependency.package_id)
+                    .or_default()
+                    .extend(&dependency.extra);
+            }
+        }
+        let excluded_edges = nodes
+            .iter()
+            .flat_map(|node| {
+                let is_root = target.roots().contains(&node.package.id.name);
+                let optional_dependencies = node
+                    .package
+                    .optional_dependencies
+                    .iter()
+                    .filter(|(extra, _)| {
+                        enabled_extras
+                            .get(&node.package.id)
+                            .is_some_and(|enabled| enabled.contains(extra))
+                    })
+                    .flat_map(|(_, dependencies)| dependencies);
+                let dependency_groups = node
+                    .package
+                    .dependency_groups
+                    .iter()
+                    .filter(move |(group, _)| is_root && groups.contains(group))
+                    .flat_map(|(_, dependencies)| dependencies);
+                node.package
+                    .dependencies
+                    .iter()
+                    .chain(optional_dependencies)
+                    .chain(dependency_groups)
+                    .map(move |dependency| (&node.package.id, &dependency.package_id))
+            })
+            // The root itself may be excluded (e.g., with `--no-emit-project`), leaving the main
+            // component incomplete.
+            .chain(root.map(|root| (&root.id, &root.id)))
+            .filter(|(_, dependency_id)| is_excluded(dependency_id));
+        for (id, dependency_id) in excluded_edges {
+            if dependency_id.source.is_local() {
+                excluded_first_party = true;
+            } else {
+                excluded_third_party = true;
+            }
+            if let Some(bom_ref) = component_builder
+                .get_component(id)
+                .and_then(|component| component.bom_ref.clone())
+            {
+                incomplete_bom_refs.insert(bom_ref);
+            }
+        }
+        let aggregate = match (excluded_first_party, excluded_third_party) {
+            (false, false) => AggregateType::Complete,
+            (true, false) => AggregateType::IncompleteFirstPartyOnly,
+            (false, true) => AggregateType::IncompleteThirdPartyOnly,
+            (true, true) => AggregateType::Incomplete,
+        };
+
+        // Unless the top-level component is omitted, use a synthetic root in two cases:
+        // 1. With `--all-packages`: ensures no dangling components from workspace packages not depended on by the workspace root.
+        // 2. For virtual workspaces (no root project): provides an anchor for the dependency graph.
+        if top_level && (all_packages || metadata.component.is_none()) {
+            let synthetic_root = component_builder.create_synthetic_root_component(
+                root.map(ComponentBuilder::get_package_name)
+                    .unwrap_or("uv-workspace"),
+                Classification::Library,
+            );
+            let synthetic_root_bom_ref = synthetic_root
+                .bom_ref
+                .clone()
+                .expect("bom-ref should always exist");
+            let root = metadata.component.replace(synthetic_root);
+
+            let mut synthetic_root_deps = workspace_member_ids
+                .iter()
+                .filter_map(|c| component_builder.get_component(c))
+                .map(|c| c.bom_ref.clone().expect("bom-ref should always exist"))
+                .collect::<Vec<_>>();
+            if let Some(ref root_component) = root
+                && let Some(ref root_bom_ref) = root_component.bom_ref
+            {
+                synthetic_root_deps.push(root_bom_ref.clone());
+            }
+
+            if let Some(workspace_root) = root {
+                components.push(workspace_root);
+            }
+
+            dependencies.push(Dependency {
+                dependency_ref: synthetic_root_bom_ref,
+                dependencies: synthetic_root_deps
+                    .into_iter()
+                    .sorted_unstable()
+                    .unique()
+                    .collect(),
+            });
+        }
+
+        if let Some(version) = main_component_version
+            && let Some(component) = metadata.component.as_mut()
+        {
+            component.version = Some(NormalizedString::new(version));
+        }
+
+        if let Some((name, version)) = main_component
+            && let Some(component) = metadata.component.as_mut()
+        {
+            component.name = NormalizedString::new(name);
+            component.version = Some(NormalizedString::new(version));
+        }
+
+        if !workspace_packages {
+            let main_bom_ref = metadata
+                .component
+                .as_ref()
+                .and_then(|component| component.bom_ref.as_ref());
+            let workspace_bom_refs = nodes
+                .iter()
+                .filter(|node| {
+                    target.roots().contains(&node.package.id.name)
+                        || workspace_member_ids.contains(&node.package.id)
+                })
+                .filter_map(|node| component_builder.get_component(&node.package.id))
+                .filter_map(|component| component.bom_ref.clone())
+                .filter(|bom_ref| Some(bom_ref) != main_bom_ref)
+                .collect::<FxHashSet<_>>();
+            components.retain(|component| {
+                component
+                    .bom_ref
+                    .as_ref()
+                    .is_none_or(|bom_ref| !workspace_bom_refs.contains(bom_ref))
+            });
+            collapse_dependencies(&mut dependencies, &workspace_bom_refs);
+
+            // The main component is incomplete if any of the workspace members it stands in for are.
+            let incomplete_count = incomplete_bom_refs.len();
+            incomplete_bom_refs.retain(|bom_ref| !workspace_bom_refs.contains(bom_ref));
+            if incomplete_bom_refs.len() < incomplete_count
+                && let Some(main_bom_ref) = main_bom_ref
+            {
+                incomplete_bom_refs.insert(main_bom_ref.clone());
+            }
+        }
+
+        if topological {
+            components = sort_topologically(components, &dependencies);
+        }
+
+        if !uv_properties {
+            for component in metadata.component.iter_mut().chain(&mut components) {
+                component.properties = None;
+            }
+        }
+
+        // Edges are built from the registered components, so they're expected to resolve; but drop
+        // any that don't, as validators reject an SBOM with dangling references.
+        let dangling_dependencies = remove_dangling_dependencies(
+            &mut dependencies,
+            metadata.component.iter().chain(&components),
+        );
+
+        let compositions = create_compositions(&dependencies, aggregate, &incomplete_bom_refs);
+
+        // Local packages are first-party, so they're neither expected to be hashed nor to have a
+        // PURL.
+        let mut warnings = Vec::new();
+        for node in nodes
+            .iter()
+            .filter(|node| !node.package.id.source.is_local())
+        {
+            if node.package.id.version.is_none() {
+                warnings.push(SbomWarning::MissingVersion {
+                    name: node.package.id.name.clone(),
+                });
+            }
+            if node.package.hashes().is_empty() {
+                warnings.push(SbomWarning::UnhashedPackage {
+                    name: node.package.id.name.clone(),
+                    version: node.package.id.version.clone(),
+                });
+            }
+            if component_builder
+                .get_component(&node.package.id)
+                .is_some_and(|component| component.purl.is_none())
+            {
+                warnings.push(SbomWarning::MissingPurl {
+                    name: node.package.id.name.clone(),
+                    version: node.package.id.version.clone(),
+                });
+            }
+        }
+        warnings.extend(dangling_dependencies);
+
+        if let Some(reporter) = reporter {
+            for warning in &warnings {
+                reporter.on_warning(warning);
+            }
+        }
+
+        if strict && let Some(warning) = warnings.first() {
+            return Err(SbomError::Strict {
+                warning: warning.clone(),
+            });
+        }
+
+        let mut bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(components)),
+            dependencies: dependency_graph.then_some(Dependencies(dependencies)),
+            compositions: dependency_graph.then_some(compositions),
+            vulnerabilities: vulnerabilities.map(Vulnerabilities),
+            ..Bom::default()
+        };
+
+        // The serial number is a random UUID by default, but an SBOM that doesn't depend on the
+        // time of generation is identified by its content instead, such that it's reproducible.
+        if timestamp != SbomTimestamp::Now {
+            bom.serial_number = Some(content_serial_number(&bom)?);
+        }
+
+        Ok((SbomExport { bom, uv_properties }, warnings))
+    }
+}
+
+/// A flat inventory of the packages in a [`Lock`](crate::Lock), without the dependency graph.
+pub struct PackageInventory<'lock> {
+    nodes: Vec<ExportableRequirement<'lock>>,
+    licenses: Option<&'lock Licenses<'lock>>,
+}
+
+impl<'lock> PackageInventory<'lock> {
+    pub fn from_lock(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        groups: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file.
+        let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
+            target,
+            prune,
+            extras,
+            groups,
+            false,
+            install_options,
+        )?;
+
+        nodes.sort_unstable_by_key(|node| &node.package.id);
+
+        Ok(Self {
+            nodes,
+            licenses: None,
+        })
+    }
+
+    /// Populate the license of each package version, as reported by the given lookup.
+    ///
+    /// The lockfile doesn't record the license of each package; this allows callers that retrieve
+    /// them (e.g., from the package metadata) to produce an inventory suitable for license review.
+    /// Packages without a known license leave the column empty.
+    #[must_use]
+    pub fn with_licenses(mut self, licenses: &'lock Licenses<'lock>) -> Self {
+        self.licenses = Some(licenses);
+        self
+    }
+
+    /// Write the inventory as JSON Lines (NDJSON), with one JSON object per package.
+    ///
+    /// Each package is serialized and written as it's visited, rather than building the entire
+    /// document in memory.
+    pub fn write_ndjson<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for node in &self.nodes {
+            serde_json::to_writer(&mut *writer, &InventoryEntry::from(node.package))?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Write the inventory as CSV, with a header row followed by one row per package, e.g., for
+    /// review in a spreadsheet.
+    ///
+    /// The columns are `name`, `version`, `source_type`, `purl`, `license` and `hashes`, where the
+    /// hashes are separated by spaces. Missing values (e.g., the license, unless provided via
+    /// [`PackageInventory::with_licenses`]) are left empty.
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+        writer.write_record([
+            "name",
+            "version",
+            "source_type",
+            "purl",
+            "license",
+            "hashes",
+        ])?;
+        for node in &self.nodes {
+            let entry = InventoryEntry::from(node.package);
+            let license = self.licenses.and_then(|licenses| {
+                node.package
+                    .version()
+                    .and_then(|version| licenses(node.package.name(), version))
+            });
+            writer.write_record([
+                entry.name,
+                entry.version.as_deref().unwrap_or_default(),
+                entry.source,
+                entry.purl.as_deref().unwrap_or_default(),
+                license.as_deref().unwrap_or_default(),
+                &entry.hashes.join(" "),
+            ])?;
+        }
+        writer.flush()
+    }
+
+    /// Write the Package URL (PURL) of each package, one per line, sorted and deduplicated, e.g.,
+    /// for PURL-based vulnerability scanners.
+    ///
+    /// Packages without a PURL (e.g., local projects) are omitted.
+    pub fn write_purls<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let purls = self
+            .nodes
+            .iter()
+            .filter_map(|node| ComponentBuilder::create_purl(node.package, None))
+            .collect::<BTreeSet<_>>();
+        for purl in purls {
+            writeln!(writer, "{purl}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single package in a [`PackageInventory`].
+#[derive(Debug, serde::Serialize)]
+struct InventoryEntry<'lock> {
+    name: &'lock str,
+    version: Option<String>,
+    source: &'static str,
+    hashes: Vec<String>,
+    purl: Option<String>,
+}
+
+impl<'lock> From<&'lock Package> for InventoryEntry<'lock> {
+    fn from(package: &'lock Package) -> Self {
+        Self {
+            name: ComponentBuilder::get_package_name(package),
+            version: ComponentBuilder::get_version_string(package),
+            source: ComponentBuilder::get_source_type(package),
+            hashes: package.hashes().iter().map(ToString::to_string).collect(),
+            purl: ComponentBuilder::create_purl(package, None),
+        }
+    }
+}
+
+/// The `bom-ref` of the uv tool component in `metadata.tools`.
+///
+/// Package `bom-ref`s always carry a numeric suffix, so this cannot collide with a component.
+pub const TOOL_BOM_REF: &str = "uv";
+
+/// Create a property for each active install option.
+fn create_install_option_properties(install_options: &InstallOptions) -> Vec<Property> {
+    let InstallOptions {
+        no_install_project,
+        only_install_project,
+        no_install_workspace,
+        only_install_workspace,
+        no_install_local,
+        only_install_local,
+        no_install_package,
+        only_install_package,
+    } = install_options;
+    [
+        ("no_project", *no_install_project),
+        ("only_project", *only_install_project),
+        ("no_workspace", *no_install_workspace),
+        ("only_workspace", *only_install_workspace),
+        ("no_local", *no_install_local),
+        ("only_local", *only_install_local),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(option, _)| UvProperty::InstallOption(option).with_value("true"))
+    .chain(
+        [
+            ("no_package", no_install_package),
+            ("only_package", only_install_package),
+        ]
+        .into_iter()
+        .filter(|(_, packages)| !packages.is_empty())
+        .map(|(option, packages)| {
+            UvProperty::InstallOption(option).with_value(&packages.iter().join(","))
+        }),
+    )
+    .collect()
+}
+
+/// The namespace from which the serial numbers of reproducible SBOMs are derived.
+const SERIAL_NUMBER_NAMESPACE: Uuid = Uuid::from_u128(0xdcc5_679d_3ff0_4aa7_ba92_ce23_ff13_b503);
+
+/// Derive the serial number of a reproducible SBOM from its contents, excluding any existing serial
+/// number.
+fn content_serial_number(bom: &Bom) -> Result<UrnUuid, JsonWriteError> {
+    let mut content = Vec::new();
+    Bom {
+        serial_number: None,
+        ..bom.clone()
+    }
+    .output_as_json_v1_5(&mut content)?;
+    Ok(UrnUuid::from(Uuid::new_v5(
+        &SERIAL_NUMBER_NAMESPACE,
+        &content,
+    )))
+}
+
+/// Create the `metadata.tools` entry identifying uv as the generator of the SBOM.
+///
+/// uv is recorded as a component (rather than a legacy tool entry) so that it carries a stable
+/// `bom-ref` that other parts of the SBOM can reference. The version of uv is omitted unless
+/// `tool_version` is set.
+fn create_tools(tool_version: bool, tools: &[SbomTool]) -> Tools {
+    let mut component = Component::new(
+        Classification::Application,
+        "uv",
+        uv_version::version(),
+        Some(TOOL_BOM_REF.to_string()),
+    );
+    if !tool_version {
+        component.version = None;
+    }
+    component.supplier = Some(OrganizationalEntity {
+        bom_ref: None,
+        name: Some(NormalizedString::new("Astral Software Inc.")),
+        url: None,
+        contact: None,
+    });
+    Tools::Object {
+        services: None,
+        components: Some(Components(
+            std::iter::once(component)
+                .chain(tools.iter().map(Component::from))
+                .collect(),
+        )),
+    }
+}
+
+/// Convert tools declared as components into the legacy list of tools, for versions of the
+/// specification before v1.5.
+fn legacy_tools(components: Option<&Components>) -> Vec<Tool> {
+    components
+        .iter()
+        .flat_map(|components| &components.0)
+        .map(|component| Tool {
+            vendor: component
+                .supplier
+                .as_ref()
+                .and_then(|supplier| supplier.name.clone()),
+            name: Some(component.name.clone()),
+            version: component.version.clone(),
+            ..Tool::default()
+        })
+        .collect()
+}
+
+/// Mark a component as sharing its PURL with a component of different content, unless it's already
+/// marked.
+fn mark_duplicate_version(component: &mut Component) {
+    let properties = &mut component
+        .properties
+        .get_or_insert_with(|| Properties(Vec::new()))
+        .0;
+    let name = UvProperty::DuplicateVersion.to_string();
+    if !properties.iter().any(|property| property.name == name) {
+        properties.push(UvProperty::DuplicateVersion.with_value("true"));
+    }
+}
+
+/// Determine the packages that are directly required by the roots, through their dependencies,
+/// enabled extras, or enabled dependency groups, or by the workspace root.
+fn direct_dependency_ids<'lock>(
+    target: &impl Installable<'lock>,
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+) -> FxHashSet<&'lock PackageId> {
+    let lock = target.lock();
+    let mut direct_dependencies = FxHashSet::default();
+
+    for root_name in target.roots() {
+        let Ok(Some(dist)) = lock.find_by_name(root_name) else {
+            continue;
+        };
+        if groups.prod() {
+            direct_dependencies.extend(
+                dist.dependencies
+                    .iter()
+                    .chain(
+                        extras
+                            .extra_names(dist.optional_dependencies.keys())
+                            .filter_map(|extra| dist.optional_dependencies.get(extra))
+                            .flatten(),
+                    )
+                    .map(|dependency| &dependency.package_id),
+            );
+        }
+        direct_dependencies.extend(
+            dist.dependency_groups
+                .iter()
+                .filter(|(group, _)| groups.contains(group))
+                .flat_map(|(_, dependencies)| dependencies)
+                .map(|dependency| &dependency.package_id),
+        );
+    }
+
+    let root_requirements = lock.requirements().iter().chain(
+        lock.dependency_groups()
+            .iter()
+            .filter(|(group, _)| groups.contains(group))
+            .flat_map(|(_, requirements)| requirements),
+    );
+    for requirement in root_requirements {
+        direct_dependencies.extend(
+            lock.packages()
+                .iter()
+                .filter(|package| package.id.name == requirement.name)
+                .map(|package| &package.id),
+        );
+    }
+
+    direct_dependencies
+}
+
+/// Collect the version specifiers with which the roots request each of their dependencies, in the
+/// lockfile's format (e.g., `>=2.0,<3`).
+fn requested_specifiers<'lock>(
+    target: &impl Installable<'lock>,
+    groups: &DependencyGroupsWithDefaults,
+) -> FxHashMap<&'lock PackageName, BTreeSet<String>> {
+    let lock = target.lock();
+
+    let package_requirements = target
+        .roots()
+        .filter_map(|root_name| lock.find_by_name(root_name).ok().flatten())
+        .flat_map(|package| {
+            package
+                .metadata
+                .requires_dist
+                .iter()
+                .filter(|_| groups.prod())
+                .chain(
+                    package
+                        .dependency_groups()
+                        .iter()
+                        .filter(|(group, _)| groups.contains(group))
+                        .flat_map(|(_, requirements)| requirements),
+                )
+        });
+    let root_requirements = lock.requirements().iter().chain(
+        lock.dependency_groups()
+            .iter()
+            .filter(|(group, _)| groups.contains(group))
+            .flat_map(|(_, requirements)| requirements),
+    );
+
+    let mut requested_specifiers: FxHashMap<&PackageName, BTreeSet<String>> = FxHashMap::default();
+    for requirement in package_requirements.chain(root_requirements) {
+        if let RequirementSource::Registry { specifier, .. } = &requirement.source
+            && !specifier.is_empty()
+        {
+            requested_specifiers
+                .entry(&requirement.name)
+                .or_default()
+                .insert(specifier.iter().join(","));
+        }
+    }
+    requested_specifiers
+}
+
+/// Determine the packages that are (transitively) required by the roots at runtime, i.e., through
+/// their dependencies and enabled extras, rather than only through dependency groups.
+fn runtime_package_ids<'lock>(
+    target: &impl Installable<'lock>,
+    prune: &[PackageName],
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+) -> FxHashSet<&'lock PackageId> {
+    let lock = target.lock();
+
+    let mut runtime_packages = FxHashSet::default();
+    let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
+    for root_name in target.roots() {
+        if prune.contains(root_name) {
+            continue;
+        }
+        let Ok(Some(dist)) = lock.find_by_name(root_name) else {
+            continue;
+        };
+        runtime_packages.insert(&dist.id);
+        if groups.prod() {
+            queue.push_back((dist, None));
+            for extra in extras.extra_names(dist.optional_dependencies.keys()) {
+                queue.push_back((dist, Some(extra)));
+            }
+        }
+    }
+    if groups.prod() {
+        for requirement in lock.requirements() {
+            if prune.contains(&requirement.name) {
+                continue;
+            }
+            for dist in lock
+                .packages()
+                .iter()
+                .filter(|package| package.id.name == requirement.name)
+            {
+                runtime_packages.insert(&dist.id);
+                queue.push_back((dist, None));
+                for extra in &requirement.extras {
+                    queue.push_back((dist, Some(extra)));
+                }
+            }
+        }
+    }
+
+    let mut seen = FxHashSet::default();
+    while let Some((package, extra)) = queue.pop_front() {
+        if !seen.insert((&package.id, extra)) {
+            continue;
+        }
+        let dependencies = if let Some(extra) = extra {
+            package.optional_dependencies.get(extra)
+        } else {
+            Some(&package.dependencies)
+        };
+        for dependency in dependencies.into_iter().flatten() {
+            if prune.contains(&dependency.package_id.name) {
+                continue;
+            }
+            let dependency_dist = lock.find_by_id(&dependency.package_id);
+            runtime_packages.insert(&dependency_dist.id);
+            queue.push_back((dependency_dist, None));
+            for extra in &dependency.extra {
+                queue.push_back((dependency_dist, Some(extra)));
+            }
+        }
+    }
+
+    runtime_packages
+}
+
+/// Determine the enabled dependency groups through which each package is (transitively) required.
+fn groups_by_package<'lock>(
+    target: &impl Installable<'lock>,
+    prune: &[PackageName],
+    groups: &DependencyGroupsWithDefaults,
+) -> FxHashMap<&'lock PackageId, BTreeSet<&'lock GroupName>> {
+    let lock = target.lock();
+
+    // Collect the direct dependencies of each enabled group, on both the root packages and the
+    // workspace root (e.g., dependency groups in non-project workspace roots).
+    let mut group_dependencies: Vec<(&GroupName, &Package, Option<&ExtraName>)> = Vec::new();
+    for root_name in target.roots() {
+        if prune.contains(root_name) {
+            continue;
+        }
+        let Ok(Some(dist)) = lock.find_by_name(root_name) else {
+            continue;
+        };
+        for (group, dependencies) in &dist.dependency_groups {
+            if !groups.contains(group) {
+                continue;
+            }
+            for dependency in dependencies {
+                if prune.contains(&dependency.package_id.name) {
+                    continue;
+                }
+                let dependency_dist = lock.find_by_id(&dependency.package_id);
+                group_dependencies.push((group, dependency_dist, None));
+                for extra in &dependency.extra {
+                    group_dependencies.push((group, dependency_dist, Some(extra)));
+                }
+            }
+        }
+    }
+    for (group, requirements) in lock.dependency_groups() {
+        if !groups.contains(group) {
+            continue;
+        }
+        for requirement in requirements {
+            if prune.contains(&requirement.name) {
+                continue;
+            }
+            for dist in lock
+                .packages()
+                .iter()
+                .filter(|package| package.id.name == requirement.name)
+            {
+                group_dependencies.push((group, dist, None));
+                for extra in &requirement.extras {
+                    group_dependencies.push((group, dist, Some(extra)));
+                }
+            }
+        }
+    }
+
+    // Walk the dependencies of each group, recording the group against every package reached.
+    let mut groups_by_package: FxHashMap<&PackageId, BTreeSet<&GroupName>> = FxHashMap::default();
+    let mut seen = FxHashSet::default();
+    let mut queue = VecDeque::from(group_dependencies);
+    while let Some((group, package, extra)) = queue.pop_front() {
+        if !seen.insert((group, &package.id, extra)) {
+            continue;
+        }
+        groups_by_package
+            .entry(&package.id)
+            .or_default()
+            .insert(group);
+
+        let dependencies = if let Some(extra) = extra {
+            package.optional_dependencies.get(extra)
+        } else {
+            Some(&package.dependencies)
+        };
+        for dependency in dependencies.into_iter().flatten() {
+            if prune.contains(&dependency.package_id.name) {
+                continue;
+            }
+            let dependency_dist = lock.find_by_id(&dependency.package_id);
+            queue.push_back((group, dependency_dist, None));
+            for extra in &dependency.extra {
+                queue.push_back((group, dependency_dist, Some(extra)));
+            }
+        }
+    }
+
+    groups_by_package
+}
+
+/// Strip the whitespace outside of strings from a JSON document.
+///
+/// `cyclonedx-bom` only supports pretty-printed output, and round-tripping through
+/// [`serde_json::Value`] would reorder the keys.
+fn minify_json(json: &[u8]) -> Vec<u8> {
+    let mut minified = Vec::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for &byte in json {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+        } else if byte == b'"' {
+            in_string = true;
+        } else if byte.is_ascii_whitespace() {
+            continue;
+        }
+        minified.push(byte);
+    }
+    minified
+}
+
+/// The SBOM is generated from a resolved lockfile, so it describes the `build` phase.
+fn create_lifecycles() -> Lifecycles {
+    Lifecycles(vec![Lifecycle::Phase(Phase::Build)])
+}
+
+fn create_dependencies(
+    nodes: &[ExportableRequirement<'_>],
+    component_builder: &ComponentBuilder,
+    marker_environment: Option<&MarkerEnvironment>,
+) -> Vec<Dependency> {
+    nodes
+        .iter()
+        .map(|node| {
+            let component = component_builder
+                .get_component(&node.package.id)
+                .expect("All nodes should have been added to map");
+
+            let immediate_deps = &node.package.dependencies;
+            let optional_deps = node.package.optional_dependencies.values().flatten();
+            let dep_groups = node.package.dependency_groups.values().flatten();
+
+            let package_deps = immediate_deps
+                .iter()
+                .chain(optional_deps)
+                .chain(dep_groups)
+                // Omit the edges that don't apply to the target environment, if any, even if the
+                // package is retained through another edge.
+                .filter(|dep| {
+                    marker_environment.is_none_or(|marker_environment| {
+                        dep.complexified_marker
+                            .evaluate_no_extras(marker_environment)
+                    })
+                })
+                .filter_map(|dep| component_builder.get_component(&dep.package_id));
+
+            let bom_refs = package_deps
+                .map(|p| p.bom_ref.clone().expect("bom-ref should always exist"))
+                .sorted_unstable()
+                .unique()
+                .collect();
+
+            Dependency {
+                dependency_ref: component
+                    .bom_ref
+                    .clone()
+                    .expect("bom-ref should always exist"),
+                dependencies: bom_refs,
+            }
+        })
+        .collect()
+}
+
+/// Remove the edges of the dependency graph that reference a component other than the given
+/// components, returning a warning for each.
+fn remove_dangling_dependencies<'a>(
+    dependencies: &mut [Dependency],
+    components: impl IntoIterator<Item = &'a Component>,
+) -> Vec<SbomWarning> {
+    let bom_refs = components
+        .into_iter()
+        .filter_map(|component| component.bom_ref.as_deref())
+        .collect::<FxHashSet<_>>();
+    let mut warnings = Vec::new();
+    for dependency in dependencies {
+        dependency.dependencies.retain(|bom_ref| {
+            if bom_refs.contains(bom_ref.as_str()) {
+                return true;
+            }
+            warnings.push(SbomWarning::DanglingDependency {
+                bom_ref: dependency.dependency_ref.clone(),
+                dependency: bom_ref.clone(),
+            });
+            false
+        });
+    }
+    warnings
+}
+
+/// Remove the given components from the dependency graph, such that each component that depended on
+/// them depends directly on their dependencies instead.
+fn collapse_dependencies(dependencies: &mut Vec<Dependency>, collapsed: &FxHashSet<String>) {
+    let collapsed_dependencies = dependencies
+        .iter()
+        .filter(|dependency| collapsed.contains(&dependency.dependency_ref))
+        .map(|dependency| {
+            (
+                dependency.dependency_ref.clone(),
+                dependency.dependencies.clone(),
+            )
+        })
+        .collect::<FxHashMap<_, _>>();
+    dependencies.retain(|dependency| !collapsed.contains(&dependency.dependency_ref));
+
+    for dependency in dependencies {
+        let mut bom_refs = BTreeSet::new();
+        let mut seen = FxHashSet::default();
+        let mut queue = std::mem::take(&mut dependency.dependencies)
+            .into_iter()
+            .collect::<VecDeque<_>>();
+        while let Some(bom_ref) = queue.pop_front() {
+            if !collapsed.contains(&bom_ref) {
+                bom_refs.insert(bom_ref);
+            } else if seen.insert(bom_ref.clone()) {
+                queue.extend(
+                    collapsed_dependencies
+                        .get(&bom_ref)
+                        .into_iter()
+                        .flatten()
+                        .cloned(),
+                );
+            }
+        }
+        bom_refs.remove(&dependency.dependency_ref);
+        dependency.dependencies = bom_refs.into_iter().collect();
+    }
+}
+
+/// Order the components such that each is declared after the components it depends on.
+///
+/// Whenever the remaining components form a cycle, the one with the lowest bom-ref is declared
+/// first, such that the order is deterministic.
+fn sort_topologically(components: Vec<Component>, dependencies: &[Dependency]) -> Vec<Component> {
+    let bom_refs = components
+        .iter()
+        .map(|component| component.bom_ref.as_deref().unwrap_or_default())
+        .collect::<Vec<_>>();
+    let indices = bom_refs
+        .iter()
+        .enumerate()
+        .map(|(index, bom_ref)| (*bom_ref, index))
+        .collect::<FxHashMap<_, _>>();
+
+    // Track the number of undeclared dependencies of each component, along with the dependents
+    // of each component.
+    let mut pending = vec![0usize; components.len()];
+    let mut dependents = vec![Vec::new(); components.len()];
+    for dependency in dependencies {
+        let Some(&dependent) = indices.get(dependency.dependency_ref.as_str()) else {
+            continue;
+        };
+        for bom_ref in &dependency.dependencies {
+            if let Some(&index) = indices.get(bom_ref.as_str())
+                && index != dependent
+            {
+                pending[dependent] += 1;
+                dependents[index].push(dependent);
+            }
+        }
+    }
+
+    let mut remaining = bom_refs
+        .iter()
+        .enumerate()
+        .map(|(index, bom_ref)| (*bom_ref, index))
+        .collect::<BTreeSet<_>>();
+    let mut ready = remaining
+        .iter()
+        .filter(|(_, index)| pending[*index] == 0)
+        .copied()
+        .collect::<BTreeSet<_>>();
+    let mut order = Vec::with_capacity(components.len());
+    while let Some(next) = ready.pop_first().or_else(|| remaining.first().copied()) {
+        remaining.remove(&next);
+        let (_, index) = next;
+        order.push(index);
+        for &dependent in &dependents[index] {
+            let key = (bom_refs[dependent], dependent);
+            if remaining.contains(&key) {
+                pending[dependent] -= 1;
+                if pending[dependent] == 0 {
+                    ready.insert(key);
+                }
+            }
+        }
+    }
+
+    let mut components = components.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .into_iter()
+        .filter_map(|index| components[index].take())
+        .collect()
+}
+
+/// Declare the completeness of the dependency graph: the components with the given bom-refs are
+/// described by the (incomplete) `aggregate`, while all other components are complete.
+fn create_compositions(
+    dependencies: &[Dependency],
+    aggregate: AggregateType,
+    incomplete_bom_refs: &BTreeSet<String>,
+) -> Compositions {
+    let complete_bom_refs = dependencies
+        .iter()
+        .map(|dependency| &dependency.dependency_ref)
+        .filter(|bom_ref| !incomplete_bom_refs.contains(*bom_ref))
+        .sorted_unstable()
+        .map(BomReference::new)
+        .collect::<Vec<_>>();
+    let composition = |aggregate, bom_refs: Vec<BomReference>| Composition {
+        bom_ref: None,
+        aggregate,
+        assemblies: None,
+        dependencies: Some(bom_refs),
+        vulnerabilities: None,
+        signature: None,
+    };
+
+    let mut compositions = Vec::new();
+    if !complete_bom_refs.is_empty() {
+        compositions.push(composition(AggregateType::Complete, complete_bom_refs));
+    }
+    if !incomplete_bom_refs.is_empty() {
+        compositions.push(composition(
+            aggregate,
+            incomplete_bom_refs.iter().map(BomReference::new).collect(),
+        ));
+    }
+    Compositions(compositions)
+}
+
+/// Create a vulnerability for each identifier reported by the advisories of the exported packages,
+/// targeting every affected component.
+fn create_vulnerabilities(
+    nodes: &[ExportableRequirement<'_>],
+    component_builder: &ComponentBuilder,
+    advisories: &FxHashMap<&PackageId, Vec<VulnerabilityReference>>,
+) -> Vec<Vulnerability> {
+    let mut affected: BTreeMap<String, (VulnerabilityReference, Vec<String>)> = BTreeMap::new();
+    for node in nodes {
+        let Some(references) = advisories.get(&node.package.id) else {
+            continue;
+        };
+        let Some(bom_ref) = component_builder
+            .get_component(&node.package.id)
+            .and_then(|component| component.bom_ref.clone())
+        else {
+            continue;
+        };
+        for reference in references {
+            affected
+                .entry(reference.id.clone())
+                .or_insert_with(|| (reference.clone(), Vec::new()))
+                .1
+                .push(bom_ref.clone());
+        }
+    }
+
+    affected
+        .into_values()
+        .map(|(reference, bom_refs)| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new(&reference.id));
+            vulnerability.vulnerability_source = reference
+                .source
+                .map(|source| VulnerabilitySource::new(Some(source), None));
+            vulnerability.description = reference.description;
+            vulnerability.vulnerability_targets = Some(create_vulnerability_targets(bom_refs));
+            vulnerability
+        })
+        .collect()
+}
+
+/// Create the (deduplicated) targets of a vulnerability from the bom-refs of the affected components.
+fn create_vulnerability_targets(bom_refs: Vec<String>) -> VulnerabilityTargets {
+    VulnerabilityTargets(
+        bom_refs
+            .into_iter()
+            .sorted_unstable()
+            .unique()
+            .map(VulnerabilityTarget::new)
+            .collect(),
+    )
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum PackageType<'a> {
+    Root,
+    Workspace(&'a Path),
+    Dependency,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write as _;
+    use std::io::Read;
+    use std::str::FromStr;
+    use std::sync::Mutex;
+
+    use cyclonedx_bom::models::signature::Algorithm;
+    use flate2::read::GzDecoder;
+    use globset::{Glob, GlobSetBuilder};
+
+    use uv_configuration::{DependencyGroups, ExtrasSpecification};
+    use uv_normalize::{DefaultExtras, DefaultGroups};
+    use uv_pep508::MarkerEnvironmentBuilder;
+    use uv_platform_tags::{Arch, Os, Platform};
+
+    use super::*;
+
+    /// A minimal [`Installable`] over a parsed [`Lock`], rooted at the given packages.
+    struct TestTarget<'lock> {
+        lock: &'lock Lock,
+        roots: Vec<PackageName>,
+    }
+
+    impl<'lock> Installable<'lock> for TestTarget<'lock> {
+        fn install_path(&self) -> &'lock Path {
+            Path::new("")
+        }
+
+        fn lock(&self) -> &'lock Lock {
+            self.lock
+        }
+
+        fn roots(&self) -> impl Iterator<Item = &PackageName> {
+            self.roots.iter()
+        }
+
+        fn project_name(&self) -> Option<&PackageName> {
+            self.roots.first()
+        }
+    }
+
+    impl<'lock> TestTarget<'lock> {
+        /// Create a target over the given lockfile, rooted at the given packages.
+        fn new(lock: &'lock Lock, roots: &[&str]) -> Self {
+            Self {
+                lock,
+                roots: roots
+                    .iter()
+                    .map(|root| PackageName::from_str(root).unwrap())
+                    .collect(),
+            }
+        }
+    }
+
+    /// Parse the given lockfile.
+    fn parse_lock(lock: &str) -> Lock {
+        toml::from_str(lock).unwrap()
+    }
+
+    /// Create a builder for the given target, with the SBOM export preview enabled.
+    fn builder<Target>(target: &Target) -> SbomExportBuilder<'_, Target> {
+        SbomExportBuilder::new(target).with_preview(Preview::new(&[PreviewFeature::SbomExport]))
+    }
+
+    /// Export the given lockfile, rooted at `root`, with the default options.
+    fn export(lock: &str, root: &str) -> SbomExport {
+        let lock = parse_lock(lock);
+        builder(&TestTarget::new(&lock, &[root])).build().unwrap()
+    }
+
+    /// Return the metadata of the SBOM.
+    fn metadata(export: &SbomExport) -> &Metadata {
+        export.bom().metadata.as_ref().unwrap()
+    }
+
+    /// Return the component with the given name, which may be the main component.
+    fn component<'export>(export: &'export SbomExport, name: &str) -> &'export Component {
+        metadata(export)
+            .component
+            .iter()
+            .chain(components(export))
+            .find(|component| *component.name == *name)
+            .unwrap()
+    }
+
+    /// Return the components in the SBOM, in order.
+    fn components(export: &SbomExport) -> impl Iterator<Item = &Component> {
+        export
+            .bom()
+            .components
+            .iter()
+            .flat_map(|components| &components.0)
+    }
+
+    /// Return the given properties as `(name, value)` pairs.
+    fn properties(properties: Option<&Properties>) -> Vec<(String, String)> {
+        properties
+            .iter()
+            .flat_map(|properties| &properties.0)
+            .map(|property| (property.name.clone(), property.value.to_string()))
+            .collect()
+    }
+
+    /// Return the properties of the component with the given name, as `(name, value)` pairs.
+    fn component_properties(export: &SbomExport, name: &str) -> Vec<(String, String)> {
+        components(export)
+            .filter(|component| *component.name == *name)
+            .flat_map(|component| properties(component.properties.as_ref()))
+            .collect()
+    }
+
+    /// Return the properties of the SBOM metadata, as `(name, value)` pairs.
+    fn metadata_properties(export: &SbomExport) -> Vec<(String, String)> {
+        properties(metadata(export).properties.as_ref())
+    }
+
+    /// Return the value of the property with the given name, if present.
+    fn property<'a>(properties: Option<&'a Properties>, name: &str) -> Option<&'a str> {
+        properties
+            .iter()
+            .flat_map(|properties| &properties.0)
+            .find(|property| property.name == name)
+            .map(|property| &*property.value)
+    }
+
+    /// Return each `(ref, dependsOn)` entry in the dependency graph.
+    fn dependencies(export: &SbomExport) -> Vec<(&str, Vec<&str>)> {
+        export
+            .bom()
+            .dependencies
+            .iter()
+            .flat_map(|dependencies| &dependencies.0)
+            .map(|dependency| {
+                (
+                    dependency.dependency_ref.as_str(),
+                    dependency.dependencies.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// Return the aggregate of each composition in the SBOM.
+    fn aggregates(export: &SbomExport) -> Vec<AggregateType> {
+        export
+            .bom()
+            .compositions
+            .iter()
+            .flat_map(|compositions| &compositions.0)
+            .map(|composition| composition.aggregate.clone())
+            .collect()
+    }
+
+    /// Serialize the SBOM, and parse the result as a JSON document.
+    fn json(export: SbomExport) -> serde_json::Value {
+        let mut output = Vec::new();
+        export.output_as_json_v1_5(&mut output).unwrap();
+        serde_json::from_slice(&output).unwrap()
+    }
+
+    /// Return the name of the main component in the SBOM, if any.
+    fn main_component(export: &SbomExport) -> Option<String> {
+        metadata(export)
+            .component
+            .as_ref()
+            .map(|component| component.name.to_string())
+    }
+
+    /// Return the `(name, version)` of each component in the SBOM.
+    fn component_names(export: &SbomExport) -> Vec<(String, Option<String>)> {
+        components(export)
+            .map(|component| {
+                (
+                    component.name.to_string(),
+                    component.version.as_ref().map(ToString::to_string),
+                )
+            })
+            .collect()
+    }
+
+    const PROJECT_A: &str = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project-a"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+    const PROJECT_B: &str = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.7"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project-b"
+version = "0.2.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+    #[test]
+    fn merge() {
+        let merged = SbomExport::merge(&[
+            export(PROJECT_A, "project-a"),
+            export(PROJECT_B, "project-b"),
+        ])
+        .unwrap();
+
+        // The shared `urllib3` is deduplicated, while both versions of `idna` are retained.
+        assert_eq!(
+            component_names(&merged),
+            [
+                ("project-a".to_string(), Some("0.1.0".to_string())),
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("urllib3".to_string(), Some("2.2.0".to_string())),
+                ("project-b".to_string(), Some("0.2.0".to_string())),
+                ("idna".to_string(), Some("3.7".to_string())),
+            ]
+        );
+
+        let root = metadata(&merged).component.as_ref().unwrap();
+        assert_eq!(root.component_type, Classification::Application);
+
+        assert_eq!(
+            dependencies(&merged),
+            [
+                (
+                    "uv-merged-sbom-6",
+                    vec!["project-a-1@0.1.0", "project-b-4@0.2.0"]
+                ),
+                ("idna-2@3.6", vec![]),
+                ("project-a-1@0.1.0", vec!["idna-2@3.6", "urllib3-3@2.2.0"]),
+                ("urllib3-3@2.2.0", vec![]),
+                ("idna-5@3.7", vec![]),
+                ("project-b-4@0.2.0", vec!["idna-5@3.7", "urllib3-3@2.2.0"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_duplicate_version() {
+        let lock = |name: &str, hash: &str| {
+            format!(
+                r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "{name}"
+version = "0.1.0"
+source = {{ editable = "." }}
+dependencies = [
+    {{ name = "urllib3" }},
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = {{ registry = "https://pypi.org/simple" }}
+sdist = {{ url = "https://files.pythonhosted.org/packages/e2/cc/abf6746cc90bc52df4ba730f301b89b3b844d6dc133cb89a01cfe2511eb9/urllib3-2.2.0.tar.gz", hash = "sha256:{hash}", size = 291267 }}
+"#
+            )
+        };
+        let hashed_export = |name: &str, hash: &str| {
+            let lock = parse_lock(&lock(name, hash));
+            let target = TestTarget::new(&lock, &[name]);
+            builder(&target).with_hashes(true).build().unwrap()
+        };
+        let urllib3 = |merged: &SbomExport| {
+            components(merged)
+                .filter(|component| *component.name == *"urllib3")
+                .map(|component| {
+                    property(
+                        component.properties.as_ref(),
+                        "uv:package:duplicate_version",
+                    )
+                    .is_some()
+                })
+                .collect::<Vec<_>>()
+        };
+        let original = "051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20";
+        let republished = "ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224";
+
+        // The same artifact is deduplicated...
+        let merged = SbomExport::merge(&[
+            hashed_export("project-a", original),
+            hashed_export("project-b", original),
+        ])
+        .unwrap();
+        assert_eq!(urllib3(&merged), [false]);
+
+        // ...as is a component without a hash...
+        let merged = SbomExport::merge(&[
+            hashed_export("project-a", original),
+            export(PROJECT_B, "project-b"),
+        ])
+        .unwrap();
+        assert_eq!(urllib3(&merged), [false]);
+
+        // ...while different artifacts of the same version are retained, and marked.
+        let merged = SbomExport::merge(&[
+            hashed_export("project-a", original),
+            hashed_export("project-b", republished),
+        ])
+        .unwrap();
+        assert_eq!(urllib3(&merged), [true, true]);
+
+        // Without uv properties, the artifacts are retained but not marked.
+        let unmarked_export = |name: &str, hash: &str| {
+            let lock = parse_lock(&lock(name, hash));
+            let target = TestTarget::new(&lock, &[name]);
+            builder(&target)
+                .with_hashes(true)
+                .with_uv_properties(false)
+                .build()
+                .unwrap()
+        };
+        let merged = SbomExport::merge(&[
+            unmarked_export("project-a", original),
+            unmarked_export("project-b", republished),
+        ])
+        .unwrap();
+        assert_eq!(urllib3(&merged), [false, false]);
+        assert!(components(&merged).all(|component| component.properties.is_none()));
+
+        // Inputs that disagree on whether uv properties are recorded can't be merged.
+        let error = SbomExport::merge(&[
+            hashed_export("project-a", original),
+            unmarked_export("project-b", republished),
+        ])
+        .unwrap_err();
+        assert!(matches!(error, SbomError::ConflictingUvProperties));
+    }
+
+    #[test]
+    fn release_notes() {
+        let release_notes = |name: &PackageName, version: &Version| {
+            (name.as_str() == "urllib3")
+                .then(|| format!("https://github.com/urllib3/urllib3/blob/{version}/CHANGES.rst"))
+        };
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = builder(&target)
+            .with_release_notes(&release_notes)
+            .build()
+            .unwrap();
+        let references = |name: &str| {
+            component(&export, name)
+                .external_references
+                .iter()
+                .flat_map(|references| &references.0)
+                .map(|reference| {
+                    (
+                        reference.external_reference_type.to_string(),
+                        reference.url.to_string(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Packages with release notes reference them...
+        assert_eq!(
+            references("urllib3"),
+            [(
+                "release-notes".to_string(),
+                "https://github.com/urllib3/urllib3/blob/2.2.0/CHANGES.rst".to_string()
+            )]
+        );
+
+        // ...while other packages are left without references.
+        assert_eq!(references("idna"), []);
+    }
+
+    #[test]
+    fn copyrights() {
+        let copyrights = |name: &PackageName, _: &Version| {
+            (name.as_str() == "urllib3")
+                .then(|| "Copyright (c) 2008-2020 Andrey Petrov and contributors".to_string())
+        };
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = builder(&target)
+            .with_copyrights(&copyrights)
+            .build()
+            .unwrap();
+        let copyright = |name: &str| {
+            component(&export, name)
+                .copyright
+                .as_ref()
+                .map(ToString::to_string)
+        };
+
+        // Packages with a copyright notice record it...
+        assert_eq!(
+            copyright("urllib3").as_deref(),
+            Some("Copyright (c) 2008-2020 Andrey Petrov and contributors")
+        );
+
+        // ...while the field is omitted for other packages.
+        assert_eq!(copyright("idna"), None);
+    }
+
+    #[test]
+    fn advisories() {
+        let advisories = |name: &PackageName, version: &Version| {
+            if name.as_ref() == "urllib3" && *version == Version::from_str("2.2.0").unwrap() {
+                vec![VulnerabilityReference {
+                    id: "CVE-2024-37891".to_string(),
+                    source: Some("NVD".to_string()),
+                    description: None,
+                }]
+            } else {
+                vec![]
+            }
+        };
+        let export_with_advisories = |lock: &str, root: &str| {
+            let lock = parse_lock(lock);
+            let target = TestTarget::new(&lock, &[root]);
+            builder(&target)
+                .with_advisories(&advisories)
+                .build()
+                .unwrap()
+        };
+        let targets = |export: &SbomExport| {
+            export
+                .bom()
+                .vulnerabilities
+                .iter()
+                .flat_map(|vulnerabilities| &vulnerabilities.0)
+                .map(|vulnerability| {
+                    (
+                        vulnerability.id.as_ref().unwrap().to_string(),
+                        vulnerability
+                            .vulnerability_targets
+                            .iter()
+                            .flat_map(|targets| &targets.0)
+                            .map(|target| target.bom_ref.clone())
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The vulnerability references the affected component by its bom-ref.
+        let project_a = export_with_advisories(PROJECT_A, "project-a");
+        assert_eq!(
+            targets(&project_a),
+            [(
+                "CVE-2024-37891".to_string(),
+                vec!["urllib3-3@2.2.0".to_string()]
+            )]
+        );
+
+        // When merging, the vulnerability is reported once, against the shared component.
+        let merged =
+            SbomExport::merge(&[project_a, export_with_advisories(PROJECT_B, "project-b")])
+                .unwrap();
+        assert_eq!(
+            targets(&merged),
+            [(
+                "CVE-2024-37891".to_string(),
+                vec!["urllib3-3@2.2.0".to_string()]
+            )]
+        );
+
+        // Without advisories, no vulnerabilities are reported.
+        assert_eq!(export(PROJECT_A, "project-a").bom().vulnerabilities, None);
+    }
+
+    #[test]
+    fn vulnerable_only() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "requests" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let queries = Mutex::new(Vec::new());
+        let advisories = |name: &PackageName, _: &Version| {
+            queries.lock().unwrap().push(name.to_string());
+            if name.as_ref() == "urllib3" {
+                vec![VulnerabilityReference {
+                    id: "CVE-2024-37891".to_string(),
+                    source: None,
+                    description: None,
+                }]
+            } else {
+                vec![]
+            }
+        };
+        let export = builder(&target)
+            .with_advisories(&advisories)
+            .with_vulnerable_only(true)
+            .build()
+            .unwrap();
+
+        // The advisories are queried once for each package.
+        assert_eq!(
+            queries.into_inner().unwrap(),
+            ["idna", "project", "requests", "urllib3"]
+        );
+
+        // The affected package is retained along with the path to it, while unaffected packages are
+        // omitted.
+        assert_eq!(
+            component_names(&export),
+            [
+                ("requests".to_string(), Some("2.31.0".to_string())),
+                ("urllib3".to_string(), Some("2.2.0".to_string())),
+            ]
+        );
+        assert_eq!(
+            dependencies(&export),
+            [
+                ("project-1@0.1.0", vec!["requests-2@2.31.0"]),
+                ("requests-2@2.31.0", vec!["urllib3-3@2.2.0"]),
+                ("urllib3-3@2.2.0", vec![]),
+            ]
+        );
+
+        // Without advisories, the option has no effect.
+        let export = builder(&target).with_vulnerable_only(true).build().unwrap();
+        assert_eq!(component_names(&export).len(), 3);
+    }
+
+    #[test]
+    fn lifecycles() {
+        let export = export(PROJECT_A, "project-a");
+        assert_eq!(
+            metadata(&export).lifecycles.as_ref(),
+            Some(&Lifecycles(vec![Lifecycle::Phase(Phase::Build)]))
+        );
+    }
+
+    #[test]
+    fn authors() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = |authors| builder(&target).with_authors(authors).build().unwrap();
+        let authors = |export: &SbomExport| metadata(export).authors.clone();
+
+        // Without any authors, the field is omitted...
+        assert_eq!(authors(&export(&[])), None);
+
+        // ...otherwise, each author is recorded as a contact.
+        let export = export(&[
+            SbomAuthor {
+                name: "Release Engineering".to_string(),
+                email: Some("release@example.com".to_string()),
+            },
+            SbomAuthor {
+                name: "Jane Doe".to_string(),
+                email: None,
+            },
+        ]);
+        assert_eq!(
+            authors(&export),
+            Some(vec![
+                OrganizationalContact {
+                    bom_ref: None,
+                    name: Some(NormalizedString::new("Release Engineering")),
+                    email: Some(NormalizedString::new("release@example.com")),
+                    phone: None,
+                },
+                OrganizationalContact {
+                    bom_ref: None,
+                    name: Some(NormalizedString::new("Jane Doe")),
+                    email: None,
+                    phone: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let timestamp = |timestamp| {
+            let export = builder(&target).with_timestamp(timestamp).build().unwrap();
+            metadata(&export)
+                .timestamp
+                .as_ref()
+                .map(ToString::to_string)
+        };
+
+        // By default, the current time is recorded, in the same format as a fixed time...
+        let now = timestamp(SbomTimestamp::Now).unwrap();
+        assert_eq!(now, jiff::Timestamp::from_str(&now).unwrap().to_string(),);
+
+        // ...but a fixed time can be recorded instead...
+        assert_eq!(
+            timestamp(SbomTimestamp::Fixed(
+                jiff::Timestamp::from_second(1_700_000_000).unwrap()
+            ))
+            .as_deref(),
+            Some("2023-11-14T22:13:20Z")
+        );
+
+        // ...or the timestamp omitted entirely.
+        assert_eq!(timestamp(SbomTimestamp::Omit), None);
+    }
+
+    #[test]
+    fn serial_number() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let serial_number = |timestamp| {
+            let export = builder(&target).with_timestamp(timestamp).build().unwrap();
+            assert!(export.bom().validate_version(SpecVersion::V1_5).passed());
+            let serial_number = export.bom().serial_number.as_ref().unwrap().to_string();
+            Uuid::parse_str(serial_number.strip_prefix("urn:uuid:").unwrap()).unwrap()
+        };
+
+        // By default, the serial number is a random UUID...
+        let random = serial_number(SbomTimestamp::Now);
+        assert_eq!(random.get_version_num(), 4);
+        assert_ne!(random, serial_number(SbomTimestamp::Now));
+
+        // ...but a reproducible SBOM is identified by its contents.
+        let reproducible = serial_number(SbomTimestamp::Omit);
+        assert_eq!(reproducible.get_version_num(), 5);
+        assert_eq!(reproducible, serial_number(SbomTimestamp::Omit));
+        assert_ne!(
+            reproducible,
+            serial_number(SbomTimestamp::Fixed(
+                jiff::Timestamp::from_second(1_700_000_000).unwrap()
+            ))
+        );
+
+        // Recording properties after generation derives the serial number again, such that it
+        // matches the final document...
+        let mut export = builder(&target)
+            .with_timestamp(SbomTimestamp::Omit)
+            .build()
+            .unwrap();
+        let original = export.bom().serial_number.clone();
+        export.set_tool_commit("53b0f5d924110e5b26fbf09f6fd3a03d67b475b7");
+        export.set_lock_hash(PROJECT_A.as_bytes());
+        assert_ne!(export.bom().serial_number, original);
+        assert_eq!(
+            export.bom().serial_number,
+            Some(content_serial_number(export.bom()).unwrap())
+        );
+
+        // ...while a random serial number is retained.
+        let mut export = builder(&target).build().unwrap();
+        let original = export.bom().serial_number.clone();
+        export.set_tool_commit("53b0f5d924110e5b26fbf09f6fd3a03d67b475b7");
+        assert_eq!(export.bom().serial_number, original);
+    }
+
+    #[test]
+    fn requires_python() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let properties = |requires_python| {
+            metadata_properties(
+                &builder(&target)
+                    .with_requires_python(requires_python)
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        // By default, the Python requirement is omitted...
+        assert_eq!(properties(false), []);
+
+        // ...otherwise, it's recorded on the metadata.
+        assert_eq!(
+            properties(true),
+            [("uv:lock:requires_python".to_string(), ">=3.12".to_string())]
+        );
+    }
+
+    #[test]
+    fn exclude_newer() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[options]
+exclude-newer = "2024-03-25T00:00:00Z"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+        // The cutoff of a lockfile resolved with `exclude-newer` is recorded on the metadata...
+        assert_eq!(
+            metadata_properties(&export(lock, "project")),
+            [(
+                "uv:lock:exclude_newer".to_string(),
+                "2024-03-25T00:00:00Z".to_string()
+            )]
+        );
+
+        // ...but omitted otherwise.
+        assert_eq!(metadata_properties(&export(PROJECT_A, "project-a")), []);
+    }
+
+    #[test]
+    fn install_option_properties() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let properties = |install_options: &InstallOptions, enabled| {
+            metadata_properties(
+                &builder(&target)
+                    .with_install_options(install_options)
+                    .with_install_option_properties(enabled)
+                    .build()
+                    .unwrap(),
+            )
+        };
+        let install_options = InstallOptions::new(
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            vec![
+                PackageName::from_str("idna").unwrap(),
+                PackageName::from_str("urllib3").unwrap(),
+            ],
+            vec![],
+        );
+
+        // The active install options are recorded...
+        assert_eq!(
+            properties(&install_options, true),
+            [
+                ("uv:install:no_project".to_string(), "true".to_string()),
+                (
+                    "uv:install:no_package".to_string(),
+                    "idna,urllib3".to_string()
+                ),
+            ]
+        );
+
+        // ...such that nothing is recorded for the defaults...
+        assert_eq!(properties(&InstallOptions::default(), true), []);
+
+        // ...or unless requested.
+        assert_eq!(properties(&install_options, false), []);
+    }
+
+    #[test]
+    fn build_info() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let marker_environment = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: "3.12.1",
+            os_name: "posix",
+            platform_machine: "x86_64",
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system: "",
+            platform_version: "",
+            python_full_version: "3.12.1",
+            python_version: "3.12",
+            sys_platform: "linux",
+        })
+        .unwrap();
+        let properties = |builder: SbomExportBuilder<'_, TestTarget<'_>>| {
+            metadata_properties(&builder.build().unwrap())
+        };
+
+        // By default, the build isn't described...
+        assert_eq!(properties(builder(&target)), []);
+
+        // ...otherwise, the generating uv and host are recorded, along with the resolution mode.
+        assert_eq!(
+            properties(builder(&target).with_build_info(true)),
+            [
+                (
+                    "uv:build:version".to_string(),
+                    uv_version::version().to_string()
+                ),
+                ("uv:build:os".to_string(), std::env::consts::OS.to_string()),
+                (
+                    "uv:build:arch".to_string(),
+                    std::env::consts::ARCH.to_string()
+                ),
+                ("uv:build:resolution".to_string(), "universal".to_string()),
+            ]
+        );
+
+        // An SBOM limited to a single environment is platform-specific.
+        let export = builder(&target)
+            .with_build_info(true)
+            .with_marker_environment(&marker_environment)
+            .build()
+            .unwrap();
+        assert_eq!(
+            property(metadata(&export).properties.as_ref(), "uv:build:resolution"),
+            Some("platform")
+        );
+    }
+
+    #[test]
+    fn tool_version() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let tool = |tool_version| {
+            let export = builder(&target)
+                .with_tool_version(tool_version)
+                .build()
+                .unwrap();
+            let Some(Tools::Object {
+                components: Some(Components(tools)),
+                ..
+            }) = metadata(&export).tools.clone()
+            else {
+                panic!("expected `metadata.tools` to list uv as a component");
+            };
+            tools.into_iter().next().unwrap()
+        };
+
+        // By default, the version of uv is recorded...
+        let component = tool(true);
+        assert_eq!(
+            component.version,
+            Some(NormalizedString::new(uv_version::version()))
+        );
+
+        // ...otherwise, uv is identified without its version.
+        let component = tool(false);
+        assert_eq!(component.name.to_string(), "uv");
+        assert_eq!(component.bom_ref.as_deref(), Some(TOOL_BOM_REF));
+        assert_eq!(component.version, None);
+    }
+
+    #[test]
+    fn tools() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let tools = [
+            SbomTool {
+                name: "acme-cli".to_string(),
+                version: Some("2.0.0".to_string()),
+                supplier: Some("Acme Corp.".to_string()),
+            },
+            SbomTool {
+                name: "acme-plugin".to_string(),
+                version: None,
+                supplier: None,
+            },
+        ];
+        let export = builder(&target).with_tools(&tools).build().unwrap();
+        let Some(Tools::Object {
+            components: Some(Components(components)),
+            ..
+        }) = metadata(&export).tools.clone()
+        else {
+            panic!("expected `metadata.tools` to list the tools as components");
+        };
+
+        // The tools are recorded after uv, which is still credited as the generator.
+        assert_eq!(
+            components
+                .iter()
+                .map(|component| (
+                    component.name.to_string(),
+                    component.version.as_ref().map(ToString::to_string),
+                    component
+                        .supplier
+                        .as_ref()
+                        .and_then(|supplier| supplier.name.as_ref())
+                        .map(ToString::to_string),
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "uv".to_string(),
+                    Some(uv_version::version().to_string()),
+                    Some("Astral Software Inc.".to_string()),
+                ),
+                (
+                    "acme-cli".to_string(),
+                    Some("2.0.0".to_string()),
+                    Some("Acme Corp.".to_string()),
+                ),
+                ("acme-plugin".to_string(), None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn tool_bom_ref() {
+        let export = export(PROJECT_A, "project-a");
+        let Some(Tools::Object {
+            components: Some(Components(tools)),
+            ..
+        }) = metadata(&export).tools.as_ref()
+        else {
+            panic!("expected `metadata.tools` to list uv as a component");
+        };
+        let [tool] = tools.as_slice() else {
+            panic!("expected a single tool, found {}", tools.len());
+        };
+        assert_eq!(tool.name.to_string(), "uv");
+        assert_eq!(
+            tool.bom_ref.as_deref(),
+            Some(TOOL_BOM_REF),
+            "the tool must carry a stable `bom-ref`"
+        );
+        assert!(
+            components(&export).all(|component| component.bom_ref.as_deref() != Some(TOOL_BOM_REF)),
+            "the tool `bom-ref` must not collide with a package"
+        );
+    }
+
+    #[test]
+    fn tool_commit() {
+        let mut export = export(PROJECT_A, "project-a");
+        export.set_tool_commit("0000000000000000000000000000000000000000");
+
+        // Setting the commit again replaces it, rather than recording it twice.
+        export.set_tool_commit("53b0f5d924110e5b26fbf09f6fd3a03d67b475b7");
+        assert_eq!(
+            metadata_properties(&export),
+            [(
+                "uv:tool:commit".to_string(),
+                "53b0f5d924110e5b26fbf09f6fd3a03d67b475b7".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn from_json_diff() {
+        let old = export(PROJECT_A, "project-a");
+        let mut json = Vec::new();
+        old.clone().output_as_json_v1_5(&mut json).unwrap();
+        let old = SbomExport::from_json(std::str::from_utf8(&json).unwrap()).unwrap();
+
+        // A round-tripped SBOM is identical to a freshly generated one.
+        assert!(old.diff(&export(PROJECT_A, "project-a")).is_empty());
+
+        let diff = old.diff(&export(PROJECT_B, "project-b"));
+        assert_eq!(diff.added.keys().collect::<Vec<_>>(), ["pkg:pypi/idna@3.7"]);
+        assert_eq!(
+            diff.removed.keys().collect::<Vec<_>>(),
+            ["pkg:pypi/idna@3.6"]
+        );
+        assert!(diff.changed.is_empty());
+
+        assert!(matches!(
+            SbomExport::from_json("{}"),
+            Err(SbomError::InvalidSbom(_))
+        ));
+    }
+
+    #[test]
+    fn compact_json() {
+        let export = export(PROJECT_A, "project-a");
+        let mut pretty = Vec::new();
+        export.clone().output_as_json_v1_5(&mut pretty).unwrap();
+        let mut compact = Vec::new();
+        export.output_as_compact_json_v1_5(&mut compact).unwrap();
+
+        let compact = String::from_utf8(compact).unwrap();
+        assert!(!compact.contains('\n'));
+        assert!(compact.len() < pretty.len());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+            serde_json::from_slice::<serde_json::Value>(&pretty).unwrap()
+        );
+    }
+
+    #[test]
+    fn lock_hash() {
+        let lock_hash = |lockfile: &str| {
+            let mut export = export(PROJECT_A, "project-a");
+            export.set_lock_hash(lockfile.as_bytes());
+            metadata_properties(&export)
+        };
+
+        // The hash covers the lockfile contents...
+        assert_eq!(
+            lock_hash(""),
+            [(
+                "uv:lock:hash".to_string(),
+                "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+                    .to_string()
+            )]
+        );
+
+        // ...and is stable for an unchanged lockfile, while any change to it is detected.
+        assert_eq!(lock_hash(PROJECT_A), lock_hash(PROJECT_A));
+        assert_ne!(lock_hash(PROJECT_A), lock_hash(PROJECT_B));
+
+        // Recording the hash of an updated lockfile replaces the previous hash.
+        let mut export = export(PROJECT_A, "project-a");
+        export.set_lock_hash(PROJECT_A.as_bytes());
+        export.set_lock_hash(PROJECT_B.as_bytes());
+        assert_eq!(metadata_properties(&export), lock_hash(PROJECT_B));
+    }
+
+    #[test]
+    fn signature() {
+        let mut export = export(PROJECT_A, "project-a");
+        export.set_signature(Signature::single(Algorithm::ES256, "MEUCIQD"));
+
+        let value = json(export.clone());
+        assert_eq!(
+            value["signature"],
+            serde_json::json!({ "algorithm": "ES256", "value": "MEUCIQD" })
+        );
+
+        // The signature is retained when the document is parsed.
+        let parsed = SbomExport::from_json(&value.to_string()).unwrap();
+        assert_eq!(parsed.bom().signature, export.bom().signature);
+    }
+
+    #[test]
+    fn canonical_json() {
+        let export = export(PROJECT_A, "project-a");
+        let mut pretty = Vec::new();
+        export.clone().output_as_json_v1_5(&mut pretty).unwrap();
+        let mut canonical = Vec::new();
+        export
+            .output_as_canonical_json_v1_5(&mut canonical)
+            .unwrap();
+
+        // The document is unchanged...
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&canonical).unwrap(),
+            serde_json::from_slice::<serde_json::Value>(&pretty).unwrap()
+        );
+
+        // ...but the keys of every object are sorted, rather than following the declaration order
+        // of the `CycloneDX` models (e.g., `type` before `bom-ref`).
+        let position = |json: &[u8], key: &str| {
+            std::str::from_utf8(json)
+                .unwrap()
+                .find(&format!("\"{key}\": "))
+                .unwrap()
+        };
+        assert!(position(&pretty, "type") < position(&pretty, "bom-ref"));
+        assert!(position(&canonical, "bom-ref") < position(&canonical, "type"));
+        assert!(position(&canonical, "components") < position(&canonical, "metadata"));
+    }
+
+    #[test]
+    fn spec_version() {
+        let json = |spec_version| {
+            let mut output = Vec::new();
+            export(PROJECT_A, "project-a")
+                .output_as_json(&mut output, spec_version)
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&output).unwrap()
+        };
+
+        // The document can be written for scanners that only accept an earlier version, omitting
+        // the data that version can't represent.
+        for (spec_version, expected) in [(SpecVersion::V1_3, "1.3"), (SpecVersion::V1_4, "1.4")] {
+            let json = json(spec_version);
+            assert_eq!(json["bomFormat"], "CycloneDX");
+            assert_eq!(json["specVersion"], expected);
+            assert!(json["metadata"].get("lifecycles").is_none());
+            assert_eq!(json["components"].as_array().unwrap().len(), 2);
+            // The generator is still identified, using the legacy form of the tools.
+            assert_eq!(json["metadata"]["tools"][0]["name"], "uv");
+            assert_eq!(
+                json["metadata"]["tools"][0]["vendor"],
+                "Astral Software Inc."
+            );
+        }
+
+        assert_eq!(json(SpecVersion::V1_5)["specVersion"], "1.5");
+    }
+
+    #[test]
+    fn iterators() {
+        let export = export(PROJECT_A, "project-a");
+
+        assert_eq!(
+            export
+                .components()
+                .map(|component| component.name.to_string())
+                .collect::<Vec<_>>(),
+            ["idna", "urllib3"]
+        );
+        assert_eq!(
+            export
+                .dependencies()
+                .map(|dependency| (
+                    dependency.dependency_ref.as_str(),
+                    dependency.dependencies.len()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("idna-2@3.6", 0),
+                ("project-a-1@0.1.0", 2),
+                ("urllib3-3@2.2.0", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn gzipped_json() {
+        let mut pretty = Vec::new();
+        export(PROJECT_A, "project-a")
+            .output_as_json_v1_5(&mut pretty)
+            .unwrap();
+
+        let gzipped = export(PROJECT_A, "project-a")
+            .output_as_gzipped_json_v1_5(Vec::new())
+            .unwrap();
+        let mut decompressed = Vec::new();
+        GzDecoder::new(gzipped.as_slice())
+            .read_to_end(&mut decompressed)
+            .unwrap();
+
+        // The timestamp and serial number differ between exports, so compare the parsed documents.
+        let parse = |json: &[u8]| {
+            SbomExport::from_json(std::str::from_utf8(json).unwrap())
+                .unwrap()
+                .into_bom()
+        };
+        assert_eq!(parse(&decompressed).components, parse(&pretty).components);
+        assert!(gzipped.len() < pretty.len());
+    }
+
+    #[test]
+    fn minify_json_strings() {
+        assert_eq!(
+            minify_json(
+                br#"{
+  "a b": "c \" d",
+  "e": [ 1, 2 ]
+}"#
+            ),
+            br#"{"a b":"c \" d","e":[1,2]}"#
+        );
+    }
+
+    #[test]
+    fn stable_ordering() {
+        let first = export(PROJECT_A, "project-a");
+        let second = export(PROJECT_A, "project-a");
+
+        // Components and dependencies are emitted in the same order across generations...
+        assert_eq!(first.bom().components, second.bom().components);
+        assert_eq!(first.bom().dependencies, second.bom().dependencies);
+
+        // ...and every `dependsOn` list is sorted.
+        for (_, depends_on) in dependencies(&first) {
+            assert!(depends_on.is_sorted());
+        }
+    }
+
+    #[test]
+    fn topological_order() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+    { name = "sniffio" },
+]
+
+[[package]]
+name = "fixtures"
+version = "3.0.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "testtools" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "anyio" },
+    { name = "testtools" },
+]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "testtools"
+version = "2.3.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "fixtures" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |topological| {
+            builder(&target)
+                .with_topological_order(topological)
+                .build()
+                .unwrap()
+        };
+        let names = |export: &SbomExport| {
+            component_names(export)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+
+        // By default, components are ordered by package...
+        assert_eq!(
+            names(&export(false)),
+            ["anyio", "fixtures", "idna", "sniffio", "testtools"]
+        );
+
+        // ...otherwise, dependencies are declared before their dependents, with cycles declared in
+        // order of bom-ref.
+        let export = export(true);
+        assert_eq!(
+            names(&export),
+            ["idna", "sniffio", "anyio", "fixtures", "testtools"]
+        );
+
+        // The dependency graph itself is unaffected.
+        assert_eq!(
+            export.bom().dependencies,
+            builder(&target).build().unwrap().bom().dependencies
+        );
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let json = |dependency_graph| {
+            json(
+                builder(&target)
+                    .with_dependency_graph(dependency_graph)
+                    .build()
+                    .unwrap(),
+            )
+        };
+
+        let graph = json(true);
+        assert!(graph.get("dependencies").is_some());
+        assert!(graph.get("compositions").is_some());
+
+        // Without the graph, only the inventory of components remains.
+        let inventory = json(false);
+        assert!(inventory.get("dependencies").is_none());
+        assert!(inventory.get("compositions").is_none());
+        assert_eq!(inventory["components"], graph["components"]);
+    }
+
+    #[test]
+    fn leaf_dependencies() {
+        let single = json(export(PROJECT_A, "project-a"));
+        let merged = json(
+            SbomExport::merge(&[
+                export(PROJECT_A, "project-a"),
+                export(PROJECT_B, "project-b"),
+            ])
+            .unwrap(),
+        );
+
+        for bom in [single, merged] {
+            // Every component is listed in the graph, such that a component without dependencies
+            // can be distinguished from one whose dependencies are unknown...
+            let dependencies = bom["dependencies"].as_array().unwrap();
+            let bom_refs = std::iter::once(&bom["metadata"]["component"])
+                .chain(bom["components"].as_array().unwrap())
+                .map(|component| component["bom-ref"].as_str().unwrap())
+                .collect::<Vec<_>>();
+            for bom_ref in bom_refs {
+                assert!(
+                    dependencies
+                        .iter()
+                        .any(|dependency| dependency["ref"] == bom_ref),
+                    "{bom_ref} is missing from the dependency graph"
+                );
+            }
+
+            // ...as leaf packages declare an explicitly empty `dependsOn`.
+            let idna = dependencies
+                .iter()
+                .find(|dependency| {
+                    dependency["ref"]
+                        .as_str()
+                        .is_some_and(|bom_ref| bom_ref.starts_with("idna-"))
+                })
+                .unwrap();
+            assert_eq!(idna["dependsOn"], serde_json::json!([]));
+        }
+    }
+
+    #[test]
+    fn main_component_bom_ref() {
+        let main_bom_ref = |export: &SbomExport| {
+            metadata(export)
+                .component
+                .as_ref()
+                .and_then(|component| component.bom_ref.as_ref())
+                .map(ToString::to_string)
+        };
+
+        // The main component is referenced by its name and version, independent of the time of
+        // generation.
+        let first = export(PROJECT_A, "project-a");
+        let second = export(PROJECT_A, "project-a");
+        assert_eq!(main_bom_ref(&first).as_deref(), Some("project-a-1@0.1.0"));
+        assert_eq!(main_bom_ref(&first), main_bom_ref(&second));
+    }
+
+    #[test]
+    fn omit_uv_properties() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let mut export = builder(&target).with_uv_properties(false).build().unwrap();
+
+        // Properties recorded after generation are omitted too.
+        export.set_tool_commit("53b0f5d924110e5b26fbf09f6fd3a03d67b475b7");
+        export.set_lock_hash(PROJECT_A.as_bytes());
+
+        assert!(
+            metadata(&export)
+                .component
+                .iter()
+                .chain(components(&export))
+                .all(|component| component.properties.is_none())
+        );
+        assert_eq!(metadata_properties(&export), []);
+    }
+
+    #[test]
+    fn omit_top_level() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = builder(&target).with_top_level(false).build().unwrap();
+
+        // The project is listed alongside its dependencies, rather than as the main component.
+        assert!(metadata(&export).component.is_none());
+        assert_eq!(
+            component_names(&export),
+            [
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("project-a".to_string(), Some("0.1.0".to_string())),
+                ("urllib3".to_string(), Some("2.2.0".to_string())),
+            ]
+        );
+
+        // No synthetic root is added to the dependency graph.
+        assert_eq!(
+            dependencies(&export)
+                .into_iter()
+                .map(|(bom_ref, _)| bom_ref)
+                .collect::<Vec<_>>(),
+            ["idna-1@3.6", "project-a-2@0.1.0", "urllib3-3@2.2.0"]
+        );
+    }
+
+    #[test]
+    fn omit_workspace_packages() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "project",
+    "utils",
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "utils" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "packages/utils" }
+dependencies = [
+    { name = "urllib3" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |all_packages| {
+            builder(&target)
+                .with_all_packages(all_packages)
+                .with_workspace_packages(false)
+                .build()
+                .unwrap()
+        };
+
+        // Only the third-party packages are listed, with the project depending directly on those
+        // required through the workspace member...
+        let export_project = export(false);
+        assert_eq!(main_component(&export_project).as_deref(), Some("project"));
+        assert_eq!(
+            component_names(&export_project),
+            [
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("urllib3".to_string(), Some("2.2.0".to_string())),
+            ]
+        );
+        assert_eq!(
+            dependencies(&export_project),
+            [
+                ("idna-2@3.6", vec![]),
+                ("project-1@0.1.0", vec!["idna-2@3.6", "urllib3-3@2.2.0"]),
+                ("urllib3-3@2.2.0", vec![]),
+            ]
+        );
+
+        // ...as does the synthetic root that anchors all the workspace members.
+        let export_workspace = export(true);
+        assert_eq!(
+            component_names(&export_workspace),
+            [
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("urllib3".to_string(), Some("2.2.0".to_string())),
+            ]
+        );
+        assert_eq!(
+            dependencies(&export_workspace).last(),
+            Some(&("project-5", vec!["idna-2@3.6", "urllib3-3@2.2.0"]))
+        );
+    }
+
+    #[test]
+    fn versionless_workspace_members() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "alpha",
+    "beta",
+    "project",
+]
+
+[[package]]
+name = "alpha"
+source = { editable = "packages/alpha" }
+
+[[package]]
+name = "beta"
+source = { editable = "packages/beta" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "alpha" },
+    { name = "beta" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target).build().unwrap();
+        let components = components(&export)
+            .map(|component| {
+                (
+                    component.bom_ref.clone(),
+                    component.purl.as_ref().map(ToString::to_string),
+                    property(component.properties.as_ref(), "uv:workspace:path"),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Members without a version are distinguished by the numeric prefix of their bom-ref and by
+        // their workspace path, and, being local, have no PURL on which they could collide.
+        assert_eq!(
+            components,
+            [
+                (Some("alpha-2".to_string()), None, Some("packages/alpha")),
+                (Some("beta-3".to_string()), None, Some("packages/beta")),
+            ]
+        );
+    }
+
+    #[test]
+    fn omit_top_level_workspace() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "child",
+    "utils",
+]
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { editable = "packages/child" }
+dependencies = [
+    { name = "utils" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "packages/utils" }
+dependencies = [
+    { name = "idna" },
+]
+"#,
+        );
+        let target = LockTarget::new(&lock, Path::new(""));
+        let export = builder(&target)
+            .with_all_packages(true)
+            .with_top_level(false)
+            .build()
+            .unwrap();
+
+        // Neither a main component nor a synthetic root is generated for a virtual workspace, such
+        // that only the packages themselves are described.
+        assert!(metadata(&export).component.is_none());
+        assert_eq!(
+            component_names(&export),
+            [
+                ("child".to_string(), Some("0.1.0".to_string())),
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("utils".to_string(), Some("0.1.0".to_string())),
+            ]
+        );
+        assert_eq!(
+            dependencies(&export)
+                .into_iter()
+                .map(|(bom_ref, _)| bom_ref)
+                .collect::<Vec<_>>(),
+            ["child-1@0.1.0", "idna-2@3.6", "utils-3@0.1.0"]
+        );
+
+        // The document remains valid without a main component.
+        assert!(export.bom().validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn single_project_root_depended_on_once() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = builder(&target).with_all_packages(true).build().unwrap();
+
+        // The project is both the workspace root and a workspace member, but the synthetic root
+        // depends on it exactly once.
+        let main_bom_ref = metadata(&export)
+            .component
+            .as_ref()
+            .and_then(|component| component.bom_ref.as_deref())
+            .unwrap();
+        assert!(dependencies(&export).contains(&(main_bom_ref, vec!["project-a-1@0.1.0"])));
+    }
+
+    #[test]
+    fn main_component_override() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let main_component = |builder: SbomExportBuilder<'_, TestTarget<'_>>| {
+            let export = builder.build().unwrap();
+            let component = metadata(&export).component.clone().unwrap();
+            (
+                component.name.to_string(),
+                component.version.map(|version| version.to_string()),
+            )
+        };
+
+        // By default, the main component is the root package...
+        assert_eq!(
+            main_component(builder(&target)),
+            ("project-a".to_string(), Some("0.1.0".to_string()))
+        );
+
+        // ...unless the caller overrides it.
+        assert_eq!(
+            main_component(builder(&target).with_main_component("release", "1.2.3")),
+            ("release".to_string(), Some("1.2.3".to_string()))
+        );
+
+        // The override also applies to the synthetic root.
+        assert_eq!(
+            main_component(
+                builder(&target)
+                    .with_all_packages(true)
+                    .with_main_component("release", "1.2.3")
+            ),
+            ("release".to_string(), Some("1.2.3".to_string()))
+        );
+
+        // The version can be overridden alone, e.g., to version the synthetic root...
+        assert_eq!(
+            main_component(
+                builder(&target)
+                    .with_all_packages(true)
+                    .with_main_component_version("0.1.0-3-g04df048")
+            ),
+            (
+                "project-a".to_string(),
+                Some("0.1.0-3-g04df048".to_string())
+            )
+        );
+
+        // ...while a full override takes precedence.
+        assert_eq!(
+            main_component(
+                builder(&target)
+                    .with_main_component_version("0.1.0-3-g04df048")
+                    .with_main_component("release", "1.2.3")
+            ),
+            ("release".to_string(), Some("1.2.3".to_string()))
+        );
+    }
+
+    #[test]
+    fn compositions() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let compositions = |prune: &[PackageName]| {
+            let export = builder(&target).with_prune(prune).build().unwrap();
+            export
+                .into_bom()
+                .compositions
+                .into_iter()
+                .flat_map(|compositions| compositions.0)
+                .map(|composition| {
+                    (
+                        composition.aggregate,
+                        composition
+                            .dependencies
+                            .into_iter()
+                            .flatten()
+                            .map(|bom_ref| bom_ref.0)
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // By default, the SBOM is complete...
+        assert_eq!(
+            compositions(&[]),
+            [(
+                AggregateType::Complete,
+                vec![
+                    "idna-2@3.6".to_string(),
+                    "project-a-1@0.1.0".to_string(),
+                    "urllib3-3@2.2.0".to_string(),
+                ]
+            )]
+        );
+
+        // ...but pruning a dependency leaves its dependents incomplete.
+        assert_eq!(
+            compositions(&[PackageName::from_str("urllib3").unwrap()]),
+            [
+                (AggregateType::Complete, vec!["idna-2@3.6".to_string()]),
+                (
+                    AggregateType::IncompleteThirdPartyOnly,
+                    vec!["project-a-1@0.1.0".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn compositions_optional_edges() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+]
+
+[package.optional-dependencies]
+socks = [
+    { name = "pysocks" },
+]
+
+[package.dev-dependencies]
+dev = [
+    { name = "pytest" },
+]
+
+[[package]]
+name = "pysocks"
+version = "1.7.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "pytest"
+version = "8.1.1"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let extras = ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default());
+        let groups = DependencyGroups::from_group(GroupName::from_str("dev").unwrap())
+            .with_defaults(DefaultGroups::default());
+        let aggregate = |prune: &str, extras: &ExtrasSpecificationWithDefaults| {
+            let prune = [PackageName::from_str(prune).unwrap()];
+            let export = builder(&target)
+                .with_extras(extras)
+                .with_groups(&groups)
+                .with_prune(&prune)
+                .build()
+                .unwrap();
+            aggregates(&export)
+        };
+
+        // Pruning a package required through an enabled extra or dependency group leaves the
+        // project incomplete...
+        assert!(aggregate("pysocks", &extras).contains(&AggregateType::IncompleteThirdPartyOnly));
+        assert!(aggregate("pytest", &extras).contains(&AggregateType::IncompleteThirdPartyOnly));
+
+        // ...unlike pruning one that's only required through a disabled extra.
+        assert_eq!(
+            aggregate(
+                "pysocks",
+                &ExtrasSpecification::default().with_defaults(DefaultExtras::default())
+            ),
+            [AggregateType::Complete]
+        );
+    }
+
+    #[test]
+    fn dependency_refs_resolve() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+
+        // Edges are built from the registered components, so every reference in the dependency
+        // graph resolves to a component, regardless of how bom-refs are formatted.
+        for all_packages in [false, true] {
+            let export = builder(&target)
+                .with_all_packages(all_packages)
+                .build()
+                .unwrap();
+            let bom_refs = metadata(&export)
+                .component
+                .iter()
+                .chain(components(&export))
+                .filter_map(|component| component.bom_ref.as_deref())
+                .collect::<FxHashSet<_>>();
+            for (dependency_ref, depends_on) in dependencies(&export) {
+                assert!(bom_refs.contains(dependency_ref));
+                for bom_ref in depends_on {
+                    assert!(bom_refs.contains(bom_ref), "dangling `{bom_ref}`");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dangling_dependencies() {
+        let components = [
+            Component::new(
+                Classification::Library,
+                "idna",
+                "3.6",
+                Some("idna-1@3.6".to_string()),
+            ),
+            Component::new(
+                Classification::Library,
+                "urllib3",
+                "2.2.0",
+                Some("urllib3-2@2.2.0".to_string()),
+            ),
+        ];
+        let mut dependencies = vec![
+            Dependency {
+                dependency_ref: "idna-1@3.6".to_string(),
+                dependencies: vec![],
+            },
+            Dependency {
+                dependency_ref: "urllib3-2@2.2.0".to_string(),
+                dependencies: vec!["idna-1@3.6".to_string(), "chardet-3@5.2.0".to_string()],
+            },
+        ];
+
+        // Edges to undeclared components are dropped, and reported...
+        let warnings = remove_dangling_dependencies(&mut dependencies, &components);
+        assert_eq!(
+            warnings,
+            [SbomWarning::DanglingDependency {
+                bom_ref: "urllib3-2@2.2.0".to_string(),
+                dependency: "chardet-3@5.2.0".to_string(),
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "`urllib3-2@2.2.0` depends on `chardet-3@5.2.0`, which isn't a component of the SBOM"
+        );
+
+        // ...while the remaining edges are retained.
+        assert_eq!(dependencies[0].dependencies, Vec::<String>::new());
+        assert_eq!(dependencies[1].dependencies, ["idna-1@3.6"]);
+    }
+
+    #[test]
+    fn bom_links() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "service" },
+]
+
+[[package]]
+name = "service"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+    { name = "sniffio" },
+]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |bom_links| builder(&target).with_bom_links(bom_links).build();
+
+        let link = "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1";
+        let export =
+            export(&[(PackageName::from_str("service").unwrap(), link.to_string())]).unwrap();
+
+        // The linked package references the external BOM...
+        assert_eq!(
+            component(&export, "service").external_references,
+            Some(ExternalReferences(vec![ExternalReference::new(
+                ExternalReferenceType::Bom,
+                Uri::BomLink(BomLink(link.to_string())),
+            )]))
+        );
+
+        // ...which describes its dependencies, unless they're also required elsewhere.
+        assert_eq!(
+            component_names(&export),
+            [
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("service".to_string(), Some("1.0.0".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_bom_link() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let bom_links = [(
+            PackageName::from_str("idna").unwrap(),
+            "https://example.com/sbom.json".to_string(),
+        )];
+        let result = builder(&target).with_bom_links(&bom_links).build();
+        assert!(matches!(result, Err(SbomError::InvalidBomLink { .. })));
+    }
+
+    #[test]
+    fn lock_target() {
+        // A project is rooted at the workspace root package...
+        let lock = parse_lock(PROJECT_A);
+        let target = LockTarget::new(&lock, Path::new(""));
+        let export = builder(&target).build().unwrap();
+        assert_eq!(main_component(&export).as_deref(), Some("project-a"));
+        assert_eq!(component_names(&export).len(), 2);
+
+        // ...while a virtual workspace is rooted at its members.
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "child",
+    "utils",
+]
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { editable = "packages/child" }
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "packages/utils" }
+"#,
+        );
+        let target = LockTarget::new(&lock, Path::new(""));
+        let export = builder(&target).build().unwrap();
+        assert_eq!(
+            component_names(&export),
+            [
+                ("child".to_string(), Some("0.1.0".to_string())),
+                ("utils".to_string(), Some("0.1.0".to_string())),
+            ]
+        );
+
+        // The roots can also be selected explicitly.
+        let target = LockTarget::new(&lock, Path::new(""))
+            .with_roots(vec![PackageName::from_str("utils").unwrap()]);
+        let export = builder(&target).build().unwrap();
+        assert_eq!(main_component(&export).as_deref(), Some("utils"));
+    }
+
+    #[test]
+    fn lock_target_member_install_options() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "project",
+    "worker",
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "worker" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "worker"
+version = "0.1.0"
+source = { editable = "services/worker" }
+dependencies = [
+    { name = "urllib3" },
+]
+"#,
+        );
+        let target = LockTarget::new(&lock, Path::new(""))
+            .with_roots(vec![PackageName::from_str("worker").unwrap()]);
+        let export = |install_options: &InstallOptions| {
+            builder(&target)
+                .with_install_options(install_options)
+                .build()
+                .unwrap()
+        };
+
+        // The member, rather than the workspace root, is the project that's omitted...
+        let export_no_project = export(&InstallOptions::new(
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            vec![],
+            vec![],
+        ));
+        assert_eq!(
+            main_component(&export_no_project).as_deref(),
+            Some("worker")
+        );
+        assert_eq!(
+            component_names(&export_no_project),
+            [("urllib3".to_string(), Some("2.2.0".to_string()))]
+        );
+        assert!(aggregates(&export_no_project).contains(&AggregateType::IncompleteFirstPartyOnly));
+
+        // ...or the only one that's retained.
+        let export_only_project = export(&InstallOptions::new(
+            false,
+            true,
+            false,
+            false,
+            false,
+            false,
+            vec![],
+            vec![],
+        ));
+        assert_eq!(
+            main_component(&export_only_project).as_deref(),
+            Some("worker")
+        );
+        assert!(component_names(&export_only_project).is_empty());
+    }
+
+    #[test]
+    fn member() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "api",
+    "project",
+    "worker",
+]
+
+[[package]]
+name = "api"
+version = "0.1.0"
+source = { editable = "services/api" }
+dependencies = [
+    { name = "idna" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "api" },
+    { name = "worker" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "worker"
+version = "0.1.0"
+source = { editable = "services/worker" }
+dependencies = [
+    { name = "urllib3" },
+]
+"#,
+        );
+        let target = TestTarget::new(&lock, &["project"]);
+        let member = PackageName::from_str("worker").unwrap();
+        let export = builder(&target).with_member(&member).build().unwrap();
+
+        // The member is the main component, and only the packages it requires are exported...
+        assert_eq!(main_component(&export).as_deref(), Some("worker"));
+        assert_eq!(
+            component_names(&export),
+            [("urllib3".to_string(), Some("2.2.0".to_string()))]
+        );
+
+        // ...even when exporting all packages.
+        let export = builder(&target)
+            .with_all_packages(true)
+            .with_member(&member)
+            .build()
+            .unwrap();
+        assert_eq!(main_component(&export).as_deref(), Some("worker"));
+
+        // Install options apply to the member rather than to the workspace root project.
+        let install_options =
+            InstallOptions::new(false, true, false, false, false, false, vec![], vec![]);
+        let export = builder(&target)
+            .with_install_options(&install_options)
+            .with_member(&member)
+            .build()
+            .unwrap();
+        assert_eq!(main_component(&export).as_deref(), Some("worker"));
+        assert!(component_names(&export).is_empty());
+
+        // Packages other than workspace members are rejected.
+        let dependency = PackageName::from_str("idna").unwrap();
+        let result = builder(&target).with_member(&dependency).build();
+        assert!(matches!(result, Err(SbomError::UnknownMember { .. })));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn workspace_path_property() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "child",
+    "project",
+    "utils",
+]
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { editable = "packages/child" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "child" },
+    { name = "utils" },
+]
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "/opt/utils" }
+"#;
+        let export = export(lock, "project");
+
+        // Members are located relative to the workspace root...
+        assert_eq!(
+            component_properties(&export, "child"),
+            [
+                ("uv:package:source_type".to_string(), "editable".to_string()),
+                (
+                    "uv:workspace:path".to_string(),
+                    "packages/child".to_string()
+                ),
+                ("uv:package:is_editable".to_string(), "true".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+
+        // ...and absolute paths are omitted, rather than leaking machine-specific details.
+        assert_eq!(
+            component_properties(&export, "utils"),
+            [
+                ("uv:package:source_type".to_string(), "editable".to_string()),
+                ("uv:package:is_editable".to_string(), "true".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn workspace_member_dependencies() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "child",
+    "project",
+    "utils",
+]
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { editable = "child" }
+dependencies = [
+    { name = "idna" },
+    { name = "utils" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "child" },
+]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "utils" }
+dependencies = [
+    { name = "sniffio" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project", "child", "utils"]);
+        let export = builder(&target).with_all_packages(true).build().unwrap();
+
+        // Resolve each `dependsOn` list to the names of the dependencies.
+        let names = components(&export)
+            .filter_map(|component| Some((component.bom_ref.as_deref()?, component.name.as_ref())))
+            .collect::<FxHashMap<_, _>>();
+        let dependencies = dependencies(&export);
+        let depends_on = |name: &str| {
+            dependencies
+                .iter()
+                .find(|(bom_ref, _)| names.get(bom_ref) == Some(&name))
+                .map(|(_, depends_on)| {
+                    depends_on
+                        .iter()
+                        .filter_map(|bom_ref| names.get(bom_ref).copied())
+                        .sorted()
+                        .collect::<Vec<_>>()
+                })
+                .unwrap()
+        };
+
+        // Every member depends on its own dependencies, whether they're members or not.
+        assert_eq!(depends_on("project"), ["child"]);
+        assert_eq!(depends_on("child"), ["idna", "utils"]);
+        assert_eq!(depends_on("utils"), ["sniffio"]);
+        assert!(depends_on("idna").is_empty());
+    }
+
+    #[test]
+    fn editable_property() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = |editable| {
+            builder(&target)
+                .with_editable(editable)
+                .with_top_level(false)
+                .build()
+                .unwrap()
+        };
+
+        // Editable packages are marked as such by default...
+        assert_eq!(
+            component_properties(&export(None), "project-a"),
+            [
+                ("uv:package:source_type".to_string(), "editable".to_string()),
+                ("uv:package:is_project_root".to_string(), "true".to_string()),
+                ("uv:package:is_editable".to_string(), "true".to_string()),
+            ]
+        );
+
+        // ...but not when exported as non-editable.
+        assert_eq!(
+            component_properties(&export(Some(EditableMode::NonEditable)), "project-a"),
+            [
+                ("uv:package:source_type".to_string(), "editable".to_string()),
+                ("uv:package:is_project_root".to_string(), "true".to_string()),
+            ]
+        );
+        assert_eq!(
+            component_properties(&export(None), "idna"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn component_group() {
+        let export = export(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "internal"
+version = "1.0.0"
+source = { registry = "https://pypi.example.com/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "internal" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#,
+            "project",
+        );
+
+        // Packages from a private index or a Git repository are grouped by host, while packages
+        // from PyPI are left ungrouped.
+        let groups = components(&export)
+            .map(|component| {
+                (
+                    component.name.to_string(),
+                    component.group.as_ref().map(ToString::to_string),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            [
+                ("idna".to_string(), None),
+                ("internal".to_string(), Some("pypi.example.com".to_string())),
+                ("urllib3".to_string(), Some("github.com".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn git_properties() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3.git?subdirectory=src&tag=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let export = export(lock, "project");
+        assert_eq!(
+            component_properties(&export, "urllib3"),
+            [
+                ("uv:package:source_type".to_string(), "git".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+                (
+                    "uv:git:commit".to_string(),
+                    "04df048cf4b1c3790c56e26c659db764aad62d6f".to_string()
+                ),
+                ("uv:git:reference".to_string(), "2.2.0".to_string()),
+                ("uv:git:subdirectory".to_string(), "src".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn annotate_description() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "requests" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let descriptions = |annotate| {
+            let export = builder(&target).with_annotate(annotate).build().unwrap();
+            components(&export)
+                .map(|component| {
+                    (
+                        component.name.to_string(),
+                        component.description.as_ref().map(ToString::to_string),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // With annotations, each component is described by its dependents...
+        assert_eq!(
+            descriptions(true),
+            [
+                (
+                    "idna".to_string(),
+                    Some("via project, requests".to_string())
+                ),
+                ("requests".to_string(), Some("via project".to_string())),
+            ]
+        );
+
+        // ...otherwise, the description is omitted.
+        assert_eq!(
+            descriptions(false),
+            [("idna".to_string(), None), ("requests".to_string(), None)]
+        );
+    }
+
+    #[test]
+    fn marker_property() {
+        let export = export(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "pywin32", marker = "sys_platform == 'win32'" },
+]
+
+[[package]]
+name = "pywin32"
+version = "306"
+source = { registry = "https://pypi.org/simple" }
+"#,
+            "project",
+        );
+
+        // Conditional dependencies carry the marker under which they're installed...
+        assert_eq!(
+            component_properties(&export, "pywin32"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+                (
+                    "uv:package:marker".to_string(),
+                    "sys_platform == 'win32'".to_string()
+                ),
+            ]
+        );
+
+        // ...while unconditional dependencies omit it.
+        assert_eq!(
+            component_properties(&export, "idna"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn requested_specifier_property() {
+        let export = export(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "certifi"
+version = "2024.2.2"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "requests" },
+]
+
+[package.metadata]
+requires-dist = [
+    { name = "idna", specifier = ">=3,<4" },
+    { name = "requests" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "certifi" },
+]
+"#,
+            "project",
+        );
+
+        // Direct dependencies record the constraint that produced their pinned version...
+        assert_eq!(
+            component_properties(&export, "idna"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+                (
+                    "uv:package:requested_specifier".to_string(),
+                    ">=3,<4".to_string()
+                ),
+            ]
+        );
+
+        // ...unless they were requested without one, while transitive dependencies are neither direct
+        // nor requested.
+        assert_eq!(
+            component_properties(&export, "requests"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+        assert_eq!(
+            component_properties(&export, "certifi"),
+            [("uv:package:source_type".to_string(), "registry".to_string())]
+        );
+    }
+
+    #[test]
+    fn group_properties() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "iniconfig"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+]
+
+[package.dev-dependencies]
+dev = [
+    { name = "pytest" },
+]
+
+[[package]]
+name = "pytest"
+version = "8.1.1"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+    { name = "iniconfig" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target)
+            .with_groups(
+                &DependencyGroups::from_group(GroupName::from_str("dev").unwrap())
+                    .with_defaults(DefaultGroups::default()),
+            )
+            .build()
+            .unwrap();
+        let groups = |name| {
+            property(
+                component(&export, name).properties.as_ref(),
+                "uv:package:groups",
+            )
+        };
+
+        // Packages required (transitively) through a group are annotated with the group, even if
+        // they are also production dependencies.
+        assert_eq!(groups("pytest"), Some("dev"));
+        assert_eq!(groups("iniconfig"), Some("dev"));
+        assert_eq!(groups("idna"), Some("dev"));
+    }
+
+    #[test]
+    fn categories() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "iniconfig"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+]
+
+[package.dev-dependencies]
+dev = [
+    { name = "pytest" },
+]
+
+[[package]]
+name = "pytest"
+version = "8.1.1"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "idna" },
+    { name = "iniconfig" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let categories = |enabled| {
+            let export = builder(&target)
+                .with_groups(
+                    &DependencyGroups::from_group(GroupName::from_str("dev").unwrap())
+                        .with_defaults(DefaultGroups::default()),
+                )
+                .with_categories(enabled)
+                .build()
+                .unwrap();
+            ["idna", "iniconfig", "pytest"].map(|name| {
+                property(
+                    component(&export, name).properties.as_ref(),
+                    "uv:package:category",
+                )
+                .map(ToString::to_string)
+            })
+        };
+
+        // Packages required only through a group are development dependencies, while those that
+        // are also production dependencies are required at runtime.
+        assert_eq!(
+            categories(true),
+            [
+                Some("runtime".to_string()),
+                Some("dev".to_string()),
+                Some("dev".to_string()),
+            ]
+        );
+
+        // Categories are omitted by default.
+        assert_eq!(categories(false), [None, None, None]);
+    }
+
+    #[test]
+    fn prune_patterns() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "requests" },
+    { name = "types-requests" },
+    { name = "types-setuptools" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "setuptools"
+version = "69.2.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "types-requests"
+version = "2.31.0.20240311"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "types-setuptools"
+version = "69.2.0.20240317"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "setuptools" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.1"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let names = |prune: &[PackageName], patterns: &[&str]| {
+            let mut glob_set = GlobSetBuilder::new();
+            for pattern in patterns {
+                glob_set.add(Glob::new(pattern).unwrap());
+            }
+            let prune_patterns = glob_set.build().unwrap();
+            let export = builder(&target)
+                .with_prune(prune)
+                .with_prune_patterns(&prune_patterns)
+                .build()
+                .unwrap();
+            component_names(&export)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+
+        // Without any patterns, nothing is pruned.
+        assert_eq!(
+            names(&[], &[]),
+            [
+                "requests",
+                "setuptools",
+                "types-requests",
+                "types-setuptools",
+                "urllib3"
+            ]
+        );
+
+        // The stub packages are pruned, along with the dependencies only required through them,
+        // while the dependencies they share with other packages are retained.
+        assert_eq!(names(&[], &["types-*"]), ["requests", "urllib3"]);
+
+        // Patterns are combined with the packages pruned by name.
+        assert_eq!(
+            names(&[PackageName::from_str("requests").unwrap()], &["types-*"]),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn override_pedigree() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+overrides = [{ name = "urllib3", specifier = "==2.2.0" }]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let export = export(lock, "project");
+        let notes = components(&export)
+            .map(|component| {
+                (
+                    component.name.to_string(),
+                    component
+                        .pedigree
+                        .as_ref()
+                        .and_then(|pedigree| pedigree.notes.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            notes,
+            [(
+                "urllib3".to_string(),
+                Some("Overridden by: urllib3==2.2.0".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn swid_tags() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let swid_export = builder(&target).with_swid(true).build().unwrap();
+        let tags = components(&swid_export)
+            .filter_map(|component| component.swid.as_ref())
+            .map(|swid| {
+                (
+                    swid.tag_id.as_str(),
+                    swid.name.as_str(),
+                    swid.version.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            [
+                ("idna-3.6", "idna", Some("3.6")),
+                ("urllib3-2.2.0", "urllib3", Some("2.2.0")),
+            ]
+        );
+
+        // SWID tags are omitted by default.
+        let export = export(PROJECT_A, "project-a");
+        assert!(components(&export).all(|component| component.swid.is_none()));
+    }
+
+    #[test]
+    fn summary() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target).with_hashes(true).build().unwrap();
+
+        let summary = export.summary();
+        assert_eq!(
+            summary,
+            SbomSummary {
+                components: 3,
+                hashed: 1,
+                source_types: BTreeMap::from([
+                    ("editable".to_string(), 1),
+                    ("git".to_string(), 1),
+                    ("registry".to_string(), 1),
+                ]),
+                dependencies: 2,
+            }
+        );
+        assert_eq!(summary.unhashed(), 2);
+    }
+
+    #[test]
+    fn all_hashes() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "basic-package"
+version = "0.1.0"
+source = { registry = "https://example.com/simple" }
+sdist = { url = "https://example.com/files/basic_package-0.1.0.tar.gz", hash = "md5:4a0d1ec5b3ad7bd9f5a6b0ffbfe3d7c2", size = 1000 }
+wheels = [
+    { url = "https://example.com/files/basic_package-0.1.0-py3-none-any.whl", hash = "sha256:7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82", size = 1548, zstd = { hash = "sha256:21c09ddf899e2ecc0a3d0a0ae8fb44ba50b839b899a0db47f5d30c5cc55e60c4", size = 786 } },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "basic-package" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target).with_hashes(true).build().unwrap();
+        let hashes = component(&export, "basic-package").hashes.as_ref().unwrap();
+
+        // Every hash in the lockfile is included, whatever the algorithm, including that of the
+        // zstd-compressed wheel.
+        assert_eq!(
+            hashes.0,
+            [
+                Hash {
+                    alg: HashAlgorithm::MD5,
+                    content: HashValue("4a0d1ec5b3ad7bd9f5a6b0ffbfe3d7c2".to_string()),
+                },
+                Hash {
+                    alg: HashAlgorithm::SHA_256,
+                    content: HashValue(
+                        "7b6229db79b5800e4e98a351b5628c1c8a944533a2d428aeeaa7275a30d4ea82"
+                            .to_string()
+                    ),
+                },
+                Hash {
+                    alg: HashAlgorithm::SHA_256,
+                    content: HashValue(
+                        "21c09ddf899e2ecc0a3d0a0ae8fb44ba50b839b899a0db47f5d30c5cc55e60c4"
+                            .to_string()
+                    ),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn artifact_hashes() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target).with_hashes(true).build().unwrap();
+
+        let idna = component(&export, "idna");
+        assert_eq!(
+            idna.hashes,
+            Some(Hashes(vec![
+                Hash {
+                    alg: HashAlgorithm::SHA_256,
+                    content: HashValue(
+                        "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                            .to_string()
+                    ),
+                },
+                Hash {
+                    alg: HashAlgorithm::SHA_256,
+                    content: HashValue(
+                        "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                            .to_string()
+                    ),
+                },
+            ]))
+        );
+        assert_eq!(
+            component_properties(&export, "idna"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+                (
+                    "uv:artifact:idna-3.6.tar.gz".to_string(),
+                    "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                        .to_string()
+                ),
+                (
+                    "uv:artifact:idna-3.6-py3-none-any.whl".to_string(),
+                    "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                        .to_string()
+                ),
+            ]
+        );
+
+        // Each distribution is referenced by its download URL, along with its own hash.
+        let distributions = idna
+            .external_references
+            .iter()
+            .flat_map(|references| &references.0)
+            .map(|reference| {
+                (
+                    reference.external_reference_type.to_string(),
+                    reference.url.to_string(),
+                    reference
+                        .hashes
+                        .iter()
+                        .flat_map(|hashes| &hashes.0)
+                        .map(|hash| hash.content.0.clone())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            distributions,
+            [
+                (
+                    "distribution".to_string(),
+                    "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz".to_string(),
+                    vec!["9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca".to_string()],
+                ),
+                (
+                    "distribution".to_string(),
+                    "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl".to_string(),
+                    vec!["c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f".to_string()],
+                ),
+            ]
+        );
+
+        // The identity of hashed components is attested by the lockfile...
+        let identity = idna
+            .evidence
+            .as_ref()
+            .and_then(|evidence| evidence.identity.as_ref())
+            .unwrap();
+        assert_eq!(identity.field, IdentityField::Purl);
+        assert_eq!(
+            identity.tools,
+            Some(ToolsReferences(vec![TOOL_BOM_REF.to_string()]))
+        );
+
+        // ...while components without hashes have no evidence.
+        let root = metadata(&export).component.as_ref().unwrap();
+        assert_eq!(root.evidence, None);
+    }
+
+    #[test]
+    fn wheel_tag_property() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "markupsafe"
+version = "2.1.5"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/87/5b/aae44c6655f3801e81aa3eef09dbbf012431987ba564d7231722f68df02d/MarkupSafe-2.1.5.tar.gz", hash = "sha256:d283d37a890ba4c1ae73ffadf8046435c76e7bc2247bbb63c00bd1a709c6544b", size = 19384, upload-time = "2024-02-02T16:31:22.863Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/53/bd/583bf3e4c8d6a321938c13f49d44024dbe5ed63e0a7ba127e454a66da974/MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl", hash = "sha256:8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1", size = 18215, upload-time = "2024-02-02T16:30:33.081Z" },
+    { url = "https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl", hash = "sha256:823b65d8706e32ad2df51ed89496147a42a2a6e01c13cfb6ffb8b1e92bc910bb", size = 17127, upload-time = "2024-02-02T16:30:44.418Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "markupsafe" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/e2/cc/abf6746cc90bc52df4ba730f301b89b3b844d6dc133cb89a01cfe2511eb9/urllib3-2.2.0.tar.gz", hash = "sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20", size = 291267, upload-time = "2024-01-30T18:40:30.993Z" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let tags = |os| {
+            Tags::from_env(
+                &Platform::new(os, Arch::X86_64),
+                (3, 12),
+                "cpython",
+                (3, 12),
+                false,
+                false,
+                false,
+            )
+            .unwrap()
+        };
+        let wheel_tags = |tags: &Tags| {
+            let export = builder(&target).with_tags(tags).build().unwrap();
+            ["idna", "markupsafe", "urllib3"].map(|name| {
+                property(
+                    component(&export, name).properties.as_ref(),
+                    "uv:package:wheel_tag",
+                )
+                .map(ToString::to_string)
+            })
+        };
+
+        // The wheel that would be installed on the platform is recorded for registry packages...
+        assert_eq!(
+            wheel_tags(&tags(Os::Windows)),
+            [
+                Some("py3-none-any".to_string()),
+                Some("cp312-cp312-win_amd64".to_string()),
+                None,
+            ]
+        );
+
+        // ...unless the package would be built from source on the platform.
+        assert_eq!(
+            wheel_tags(&tags(Os::Manylinux {
+                major: 2,
+                minor: 28
+            })),
+            [Some("py3-none-any".to_string()), None, None]
+        );
+
+        // Without tags, no wheel is selected.
+        assert_eq!(
+            component_properties(&export(PROJECT_A, "project-a"), "idna"),
+            [
+                ("uv:package:source_type".to_string(), "registry".to_string()),
+                ("uv:package:is_direct".to_string(), "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn download_urls() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/88/75/311454fd3317aefe18415f04568edc20218453b709c63c58b9292c71be17/urllib3-2.2.0-py3-none-any.whl", hash = "sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224", size = 120928, upload-time = "2024-01-30T18:40:28.616Z" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let download_urls = |enabled| {
+            let export = builder(&target)
+                .with_download_urls(enabled)
+                .build()
+                .unwrap();
+            ["idna", "project", "urllib3"].map(|name| {
+                property(
+                    component(&export, name).properties.as_ref(),
+                    "uv:package:download_url",
+                )
+                .map(ToString::to_string)
+            })
+        };
+
+        // Only registry packages with a source distribution have a download URL.
+        assert_eq!(
+            download_urls(true),
+            [
+                Some("https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz".to_string()),
+                None,
+                None,
+            ]
+        );
+
+        // Download URLs are omitted by default.
+        assert_eq!(download_urls(false), [None, None, None]);
+    }
+
+    #[test]
+    fn upload_times() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/e2/cc/abf6746cc90bc52df4ba730f301b89b3b844d6dc133cb89a01cfe2511eb9/urllib3-2.2.0.tar.gz", hash = "sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20", size = 291267 }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let upload_times = |enabled| {
+            let export = builder(&target).with_upload_times(enabled).build().unwrap();
+            ["idna", "project", "urllib3"].map(|name| {
+                property(
+                    component(&export, name).properties.as_ref(),
+                    "uv:package:upload_time",
+                )
+                .map(ToString::to_string)
+            })
+        };
+
+        // The release was uploaded along with its earliest distribution. Packages without a
+        // reported upload time are left without one.
+        assert_eq!(
+            upload_times(true),
+            [Some("2023-11-25T15:40:52.604Z".to_string()), None, None]
+        );
+
+        // Upload times are omitted by default.
+        assert_eq!(upload_times(false), [None, None, None]);
+    }
+
+    #[test]
+    fn distribution_components() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |enabled| {
+            builder(&target)
+                .with_distribution_components(enabled)
+                .build()
+                .unwrap()
+        };
+        let distributions = |export: &SbomExport, name: &str| {
+            component(export, name)
+                .components
+                .as_ref()
+                .map(|components| {
+                    components
+                        .0
+                        .iter()
+                        .map(|component| {
+                            (
+                                component.component_type.clone(),
+                                component.name.to_string(),
+                                component.bom_ref.clone().unwrap(),
+                                component
+                                    .hashes
+                                    .iter()
+                                    .flat_map(|hashes| &hashes.0)
+                                    .map(|hash| hash.content.0.clone())
+                                    .collect::<Vec<_>>(),
+                                component
+                                    .external_references
+                                    .iter()
+                                    .flat_map(|references| &references.0)
+                                    .map(|reference| reference.url.to_string())
+                                    .collect::<Vec<_>>(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        // Each distribution of a registry package is nested within its component...
+        let enabled = export(true);
+        assert_eq!(
+            distributions(&enabled, "idna"),
+            Some(vec![
+                (
+                    Classification::File,
+                    "idna-3.6.tar.gz".to_string(),
+                    "idna-2@3.6#idna-3.6.tar.gz".to_string(),
+                    vec![
+                        "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                            .to_string()
+                    ],
+                    vec!["https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz".to_string()],
+                ),
+                (
+                    Classification::File,
+                    "idna-3.6-py3-none-any.whl".to_string(),
+                    "idna-2@3.6#idna-3.6-py3-none-any.whl".to_string(),
+                    vec![
+                        "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                            .to_string()
+                    ],
+                    vec!["https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl".to_string()],
+                ),
+            ])
+        );
+
+        // ...with the media type of each distribution...
+        let mime_types = components(&enabled)
+            .filter_map(|component| component.components.as_ref())
+            .flat_map(|components| &components.0)
+            .map(|component| component.mime_type.as_ref().map(|mime_type| &*mime_type.0))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mime_types,
+            [Some("application/gzip"), Some("application/zip")]
+        );
+        assert_eq!(
+            ComponentBuilder::get_mime_type("idna-3.6.zip"),
+            Some("application/zip")
+        );
+        assert_eq!(
+            ComponentBuilder::get_mime_type("idna-3.6.tar.bz2"),
+            Some("application/x-bzip2")
+        );
+
+        // ...while packages from other sources have no distributions to declare.
+        assert_eq!(distributions(&enabled, "urllib3"), None);
+
+        // Distributions aren't declared by default.
+        assert_eq!(distributions(&export(false), "idna"), None);
+    }
+
+    #[test]
+    fn multiple_hashes_per_algorithm() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "markupsafe"
+version = "2.1.5"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/87/5b/aae44c6655f3801e81aa3eef09dbbf012431987ba564d7231722f68df02d/MarkupSafe-2.1.5.tar.gz", hash = "sha256:d283d37a890ba4c1ae73ffadf8046435c76e7bc2247bbb63c00bd1a709c6544b", size = 19384, upload-time = "2024-02-02T16:31:22.863Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/53/bd/583bf3e4c8d6a321938c13f49d44024dbe5ed63e0a7ba127e454a66da974/MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl", hash = "sha256:8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1", size = 18215, upload-time = "2024-02-02T16:30:33.081Z" },
+    { url = "https://files.pythonhosted.org/packages/3f/14/c3554d512d5f9100a95e737502f4a2323a1959f6d0d01e0d0997b35f7b10/MarkupSafe-2.1.5-cp312-cp312-win_amd64.whl", hash = "sha256:823b65d8706e32ad2df51ed89496147a42a2a6e01c13cfb6ffb8b1e92bc910bb", size = 17127, upload-time = "2024-02-02T16:30:44.418Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "markupsafe" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target).with_hashes(true).build().unwrap();
+
+        // Every distinct `sha256` digest is retained on the component, rather than being collapsed
+        // into a single hash per algorithm...
+        let markupsafe = component(&export, "markupsafe");
+        let digests = markupsafe
+            .hashes
+            .iter()
+            .flat_map(|hashes| &hashes.0)
+            .map(|hash| (hash.alg.to_string(), hash.content.0.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            digests,
+            [
+                (
+                    "SHA-256".to_string(),
+                    "d283d37a890ba4c1ae73ffadf8046435c76e7bc2247bbb63c00bd1a709c6544b"
+                ),
+                (
+                    "SHA-256".to_string(),
+                    "8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1"
+                ),
+                (
+                    "SHA-256".to_string(),
+                    "823b65d8706e32ad2df51ed89496147a42a2a6e01c13cfb6ffb8b1e92bc910bb"
+                ),
+            ]
+        );
+
+        // ...and each is attributed to the distribution it belongs to.
+        let artifacts = component_properties(&export, "markupsafe")
+            .into_iter()
+            .filter(|(name, _)| name.starts_with("uv:artifact:"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            artifacts,
+            [
+                (
+                    "uv:artifact:MarkupSafe-2.1.5.tar.gz".to_string(),
+                    "sha256:d283d37a890ba4c1ae73ffadf8046435c76e7bc2247bbb63c00bd1a709c6544b"
+                        .to_string()
+                ),
+                (
+                    "uv:artifact:markupsafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl"
+                        .to_string(),
+                    "sha256:8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1"
+                        .to_string()
+                ),
+                (
+                    "uv:artifact:markupsafe-2.1.5-cp312-cp312-win_amd64.whl".to_string(),
+                    "sha256:823b65d8706e32ad2df51ed89496147a42a2a6e01c13cfb6ffb8b1e92bc910bb"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unhashed_packages() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |unhashed| builder(&target).with_unhashed(unhashed).build().unwrap();
+        let is_unhashed = |export: &SbomExport, name: &str| {
+            property(
+                component(export, name).properties.as_ref(),
+                "uv:package:is_unhashed",
+            )
+            .is_some()
+        };
+
+        // By default, unhashed packages are included without being marked...
+        let included = export(UnhashedPackages::Include);
+        assert_eq!(component_names(&included).len(), 2);
+        assert!(!is_unhashed(&included, "urllib3"));
+
+        // ...but they can be marked...
+        let marked = export(UnhashedPackages::Mark);
+        assert!(is_unhashed(&marked, "urllib3"));
+        assert!(!is_unhashed(&marked, "idna"));
+
+        // ...or excluded, retaining the root.
+        let excluded = export(UnhashedPackages::Exclude);
+        assert_eq!(
+            component_names(&excluded),
+            [("idna".to_string(), Some("3.6".to_string()))]
+        );
+        assert!(metadata(&excluded).component.is_some());
+    }
+
+    #[test]
+    fn virtual_package() {
+        let export = export(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "bridge"
+version = "0.1.0"
+source = { virtual = "bridge" }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "bridge" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#,
+            "project",
+        );
+
+        // Virtual packages have no distribution, and so are identified by their source type rather
+        // than a PURL...
+        let bridge = component(&export, "bridge");
+        assert_eq!(bridge.purl, None);
+        assert_eq!(
+            component_properties(&export, "bridge")[0],
+            ("uv:package:source_type".to_string(), "virtual".to_string())
+        );
+
+        // ...while still connecting the dependencies they bridge.
+        let dependencies = dependencies(&export);
+        assert!(dependencies.contains(&("project-1@0.1.0", vec!["bridge-2@0.1.0"])));
+        assert!(dependencies.contains(&("bridge-2@0.1.0", vec!["idna-3@3.6", "urllib3-4@2.2.0"])));
+    }
+
+    #[test]
+    fn source_kinds() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { directory = "child" }
+dependencies = [
+    { name = "iniconfig" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "iniconfig"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "child" },
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = |source_kinds| {
+            builder(&target)
+                .with_source_kinds(source_kinds)
+                .build()
+                .unwrap()
+        };
+        let names = |export: &SbomExport| {
+            component_names(export)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+
+        // Only the packages from the given kinds of source are retained, regardless of the
+        // packages through which they're required...
+        let export = export(&[SourceKind::Registry]);
+        assert_eq!(names(&export), ["idna", "iniconfig"]);
+
+        // ...while the root is always retained...
+        assert!(metadata(&export).component.is_some());
+
+        // ...and edges to the omitted packages are dropped.
+        assert_eq!(
+            dependencies(&export)
+                .into_iter()
+                .flat_map(|(_, depends_on)| depends_on)
+                .collect::<Vec<_>>(),
+            ["idna-2@3.6"]
+        );
+    }
+
+    #[test]
+    fn reporter() {
+        #[derive(Default)]
+        struct RecordingReporter {
+            events: Mutex<Vec<String>>,
+        }
+
+        impl Reporter for RecordingReporter {
+            fn on_component(&self, name: &PackageName, version: Option<&Version>) {
+                let version = version.map(ToString::to_string).unwrap_or_default();
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("component: {name} {version}"));
+            }
+
+            fn on_warning(&self, warning: &SbomWarning) {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("warning: {warning}"));
+            }
+        }
+
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let reporter = RecordingReporter::default();
+        builder(&target).with_reporter(&reporter).build().unwrap();
+
+        // Each component is reported as it's created, followed by any warnings.
+        assert_eq!(
+            reporter.events.into_inner().unwrap(),
+            [
+                "component: project 0.1.0",
+                "component: idna 3.6",
+                "component: urllib3 2.2.0",
+                "warning: `urllib3==2.2.0` has no integrity hash",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let warnings = builder(&target).validate().unwrap();
+
+        // Only the remote package without a hash is reported; the local project is first-party.
+        assert_eq!(
+            warnings,
+            [SbomWarning::UnhashedPackage {
+                name: PackageName::from_str("urllib3").unwrap(),
+                version: Some(Version::from_str("2.2.0").unwrap()),
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "`urllib3==2.2.0` has no integrity hash"
+        );
+    }
+
+    #[test]
+    fn strict() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let builder = builder(&target);
+
+        // A remote package without a version is reported...
+        assert_eq!(
+            builder.validate().unwrap(),
+            [
+                SbomWarning::MissingVersion {
+                    name: PackageName::from_str("urllib3").unwrap(),
+                },
+                SbomWarning::UnhashedPackage {
+                    name: PackageName::from_str("urllib3").unwrap(),
+                    version: None,
+                },
+            ]
+        );
+        assert!(builder.build().is_ok());
+
+        // ...and rejected in strict mode.
+        let builder = builder.with_strict(true);
+        assert!(matches!(
+            builder.build(),
+            Err(SbomError::Strict {
+                warning: SbomWarning::MissingVersion { .. }
+            })
+        ));
+
+        // An SBOM without any issues is unaffected by strict mode.
+        let prune = [PackageName::from_str("urllib3").unwrap()];
+        assert!(builder.with_prune(&prune).build().is_ok());
+    }
+
+    #[test]
+    fn marker_environment() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "pywin32", marker = "sys_platform == 'win32'" },
+]
+
+[[package]]
+name = "pywin32"
+version = "306"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let marker_environment = |sys_platform| {
+            MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+                implementation_name: "cpython",
+                implementation_version: "3.12.1",
+                os_name: "posix",
+                platform_machine: "x86_64",
+                platform_python_implementation: "CPython",
+                platform_release: "",
+                platform_system: "",
+                platform_version: "",
+                python_full_version: "3.12.1",
+                python_version: "3.12",
+                sys_platform,
+            })
+            .unwrap()
+        };
+        let export = |marker_environment: Option<&MarkerEnvironment>| {
+            let builder = builder(&target);
+            match marker_environment {
+                Some(marker_environment) => builder.with_marker_environment(marker_environment),
+                None => builder,
+            }
+            .build()
+            .unwrap()
+        };
+
+        // By default, the export covers every platform...
+        assert_eq!(
+            component_names(&export(None)),
+            [
+                ("idna".to_string(), Some("3.6".to_string())),
+                ("pywin32".to_string(), Some("306".to_string())),
+            ]
+        );
+
+        // ...but it can be limited to the packages installed on a given platform.
+        let windows = marker_environment("win32");
+        assert_eq!(component_names(&export(Some(&windows))).len(), 2);
+
+        let linux = marker_environment("linux");
+        let linux = export(Some(&linux));
+        assert_eq!(
+            component_names(&linux),
+            [("idna".to_string(), Some("3.6".to_string()))]
+        );
+
+        // Packages for other platforms aren't part of the target, so the SBOM remains complete.
+        assert_eq!(aggregates(&linux), [AggregateType::Complete]);
+    }
+
+    #[test]
+    fn marker_environment_edges() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "click"
+version = "8.1.7"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "colorama" },
+]
+
+[[package]]
+name = "colorama"
+version = "0.4.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "click" },
+    { name = "colorama", marker = "sys_platform == 'win32'" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let linux = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name: "cpython",
+            implementation_version: "3.12.1",
+            os_name: "posix",
+            platform_machine: "x86_64",
+            platform_python_implementation: "CPython",
+            platform_release: "",
+            platform_system: "",
+            platform_version: "",
+            python_full_version: "3.12.1",
+            python_version: "3.12",
+            sys_platform: "linux",
+        })
+        .unwrap();
+        let export = builder(&target)
+            .with_marker_environment(&linux)
+            .build()
+            .unwrap();
+        let dependencies = export
+            .dependencies()
+            .map(|dependency| {
+                (
+                    dependency.dependency_ref.as_str(),
+                    dependency
+                        .dependencies
+                        .iter()
+                        .map(String::as_str)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The package is retained through `click`, but the edge that only applies on Windows is
+        // omitted.
+        assert_eq!(
+            dependencies,
+            [
+                ("click-2@8.1.7", vec!["colorama-3@0.4.6"]),
+                ("colorama-3@0.4.6", vec![]),
+                ("project-1@0.1.0", vec!["click-2@8.1.7"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn direct_only() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "certifi"
+version = "2024.2.2"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "requests" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "certifi" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+
+        // By default, the transitive dependency is included, but not marked as direct.
+        let export = builder(&target).build().unwrap();
+        assert_eq!(
+            property(
+                component(&export, "requests").properties.as_ref(),
+                "uv:package:is_direct"
+            ),
+            Some("true")
+        );
+        assert_eq!(
+            property(
+                component(&export, "certifi").properties.as_ref(),
+                "uv:package:is_direct"
+            ),
+            None
+        );
+
+        let export = builder(&target).with_direct_only(true).build().unwrap();
+
+        // The transitive dependency is omitted, along with the edge to it.
+        assert_eq!(
+            component_names(&export),
+            [("requests".to_string(), Some("2.31.0".to_string()))]
+        );
+        assert_eq!(
+            dependencies(&export),
+            [
+                ("project-1@0.1.0", vec!["requests-2@2.31.0"]),
+                ("requests-2@2.31.0", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn purl_encoding() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "Foo_Bar"
+version = "1!2.3"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "foo-bar" },
+    { name = "torch" },
+]
+
+[[package]]
+name = "torch"
+version = "2.2.1+cpu"
+source = { registry = "https://download.pytorch.org/whl/cpu" }
+"#;
+        let export = export(lock, "project");
+        let purls = components(&export)
+            .filter_map(|component| component.purl.as_ref())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        // Names are normalized, and epochs and local version segments are percent-encoded.
+        assert_eq!(
+            purls,
+            [
+                "pkg:pypi/foo-bar@1%212.3",
+                "pkg:pypi/torch@2.2.1%2Bcpu?repository_url=https://download.pytorch.org/whl/cpu",
+            ]
+        );
+    }
+
+    #[test]
+    fn purl_qualifiers() {
+        // Qualifiers are sorted by key, and empty values are omitted.
+        assert_eq!(
+            ComponentBuilder::format_qualifiers(&[
+                ("vcs_url", "git+https://github.com/urllib3/urllib3"),
+                ("download_url", "https://example.com/urllib3 2.2.0.tar.gz"),
+                ("checksum", ""),
+            ]),
+            "?download_url=https://example.com/urllib3%202.2.0.tar.gz&vcs_url=git%2Bhttps://github.com/urllib3/urllib3"
+        );
+    }
+
+    #[test]
+    fn injected_purl_qualifiers() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#2c4e8d4b4b4a7b0b0b0b0b0b0b0b0b0b0b0b0b0b" }
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let purl_qualifiers = BTreeMap::from([
+            (
+                "repository_url".to_string(),
+                "https://mirror.example.com/simple".to_string(),
+            ),
+            (
+                "vcs_url".to_string(),
+                "git+https://mirror.example.com".to_string(),
+            ),
+        ]);
+        let export = builder(&target)
+            .with_purl_qualifiers(&purl_qualifiers)
+            .build()
+            .unwrap();
+        let purls = metadata(&export)
+            .component
+            .iter()
+            .chain(components(&export))
+            .map(|component| {
+                (
+                    component.name.to_string(),
+                    component.purl.as_ref().map(ToString::to_string),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The qualifiers are appended in canonical form, without overriding those derived from the
+        // package's source, while the local project still has no PURL.
+        assert_eq!(
+            purls,
+            [
+                ("project".to_string(), None),
+                (
+                    "idna".to_string(),
+                    Some(
+                        "pkg:pypi/idna@3.6?repository_url=https://mirror.example.com/simple&vcs_url=git%2Bhttps://mirror.example.com"
+                            .to_string()
+                    )
+                ),
+                (
+                    "urllib3".to_string(),
+                    Some(
+                        "pkg:pypi/urllib3@2.2.0?repository_url=https://mirror.example.com/simple&vcs_url=https://github.com/urllib3/urllib3%3Frev%3D2.2.0%232c4e8d4b4b4a7b0b0b0b0b0b0b0b0b0b0b0b0b0b"
+                            .to_string()
+                    )
+                ),
+            ]
+        );
+
+        // Keys that can't be expressed in a PURL are rejected, rather than invalidating every PURL.
+        for key in ["Repository_URL", "a=b", "1st", ""] {
+            let purl_qualifiers = BTreeMap::from([(key.to_string(), "value".to_string())]);
+            let result = builder(&target)
+                .with_purl_qualifiers(&purl_qualifiers)
+                .build();
+            assert!(
+                matches!(result, Err(SbomError::InvalidPurlQualifier { key: ref invalid }) if invalid == key),
+                "{key}"
+            );
+        }
+    }
+
+    #[test]
+    fn purl_through_several_paths() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "requests" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#;
+        let export = export(lock, "project");
+        let purls = components(&export)
+            .filter(|component| *component.name == *"urllib3")
+            .filter_map(|component| component.purl.as_ref())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        // A Git dependency reached both directly and transitively has a single PURL.
+        assert_eq!(
+            purls,
+            [
+                "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f"
+            ]
+        );
+    }
+
+    #[test]
+    fn framework_component_type() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "flask"
+version = "3.0.2"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "flask" },
+    { name = "idna" },
+]
+"#;
+        let export = export(lock, "project");
+        let types = components(&export)
+            .map(|component| (component.name.to_string(), component.component_type.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                ("flask".to_string(), Classification::Framework),
+                ("idna".to_string(), Classification::Library),
+            ]
+        );
+    }
+
+    #[test]
+    fn versionless_package() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "dependency"
+source = { directory = "../dependency" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "dependency" },
+]
+"#;
+        let export = export(lock, "project");
+        let dependency = component(&export, "dependency");
+
+        // No placeholder version is synthesized for packages without a version.
+        assert_eq!(dependency.version, None);
+        assert_eq!(dependency.bom_ref.as_deref(), Some("dependency-2"));
+        assert_eq!(dependency.purl, None);
+    }
+
+    #[test]
+    fn inventory_ndjson() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/urllib3-2.2.0.tar.gz", hash = "sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20", size = 290035 }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/urllib3-2.2.0-py3-none-any.whl", hash = "sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224", size = 120893 },
+]
+"#;
+        let lock = parse_lock(lock);
+        let install_options = InstallOptions::default();
+        let target = TestTarget::new(&lock, &["project"]);
+        let inventory = PackageInventory::from_lock(
+            &target,
+            &[],
+            &ExtrasSpecification::default().with_defaults(DefaultExtras::default()),
+            &DependencyGroups::default().with_defaults(DefaultGroups::default()),
+            &install_options,
+        )
+        .unwrap();
+
+        let mut output = Vec::new();
+        inventory.write_ndjson(&mut output).unwrap();
+        insta::assert_snapshot!(String::from_utf8(output).unwrap(), @r#"
+        {"name":"project","version":"0.1.0","source":"editable","hashes":[],"purl":null}
+        {"name":"urllib3","version":"2.2.0","source":"registry","hashes":["sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20","sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224"],"purl":"pkg:pypi/urllib3@2.2.0"}
+        "#);
+    }
+
+    #[test]
+    fn inventory_csv() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/urllib3-2.2.0.tar.gz", hash = "sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20", size = 290035 }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/urllib3-2.2.0-py3-none-any.whl", hash = "sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224", size = 120893 },
+]
+"#;
+        let lock = parse_lock(lock);
+        let install_options = InstallOptions::default();
+        let target = TestTarget::new(&lock, &["project"]);
+        let inventory = PackageInventory::from_lock(
+            &target,
+            &[],
+            &ExtrasSpecification::default().with_defaults(DefaultExtras::default()),
+            &DependencyGroups::default().with_defaults(DefaultGroups::default()),
+            &install_options,
+        )
+        .unwrap();
+
+        let mut output = Vec::new();
+        inventory.write_csv(&mut output).unwrap();
+        insta::assert_snapshot!(String::from_utf8(output).unwrap(), @"
+        name,version,source_type,purl,license,hashes
+        project,0.1.0,editable,,,
+        urllib3,2.2.0,registry,pkg:pypi/urllib3@2.2.0,,sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20 sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224
+        ");
+
+        // Licenses are populated from the lookup, and quoted where necessary.
+        let licenses = |name: &PackageName, _: &Version| {
+            (name.as_str() == "urllib3").then(|| "MIT, Apache-2.0".to_string())
+        };
+        let mut output = Vec::new();
+        inventory
+            .with_licenses(&licenses)
+            .write_csv(&mut output)
+            .unwrap();
+        insta::assert_snapshot!(String::from_utf8(output).unwrap(), @r#"
+        name,version,source_type,purl,license,hashes
+        project,0.1.0,editable,,,
+        urllib3,2.2.0,registry,pkg:pypi/urllib3@2.2.0,"MIT, Apache-2.0",sha256:051d961ad0c62a94e50ecf1af379c3aba230c66c710493493560c0c223c49f20 sha256:ce3711610ddce217e6d113a2732fafad960a03fd0318c91faa79481e35c11224
+        "#);
+    }
+
+    #[test]
+    fn inventory_purls() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let lock = parse_lock(lock);
+        let install_options = InstallOptions::default();
+        let target = TestTarget::new(&lock, &["project"]);
+        let inventory = PackageInventory::from_lock(
+            &target,
+            &[],
+            &ExtrasSpecification::default().with_defaults(DefaultExtras::default()),
+            &DependencyGroups::default().with_defaults(DefaultGroups::default()),
+            &install_options,
+        )
+        .unwrap();
+
+        let mut output = Vec::new();
+        inventory.write_purls(&mut output).unwrap();
+        insta::assert_snapshot!(String::from_utf8(output).unwrap(), @"
+        pkg:pypi/idna@3.6
+        pkg:pypi/urllib3@2.2.0
+        ");
+    }
+
+    #[test]
+    fn shared_dependency_across_extras() {
+        let lock = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+
+[package.optional-dependencies]
+cli = [
+    { name = "idna" },
+]
+web = [
+    { name = "idna" },
+]
+"#;
+        let lock = parse_lock(lock);
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = builder(&target)
+            .with_extras(
+                &ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default()),
+            )
+            .build()
+            .unwrap();
+
+        // A package reached through several extras is declared once, as a single node of the
+        // dependency graph, with a single edge from the project.
+        assert_eq!(
+            component_names(&export),
+            [("idna".to_string(), Some("3.6".to_string()))]
+        );
+        assert!(dependencies(&export).contains(&("project-1@0.1.0", vec!["idna-2@3.6"])));
+    }
+
+    #[test]
+    fn generate_on_another_thread() {
+        // An owned lockfile can be moved to a blocking task, and the SBOM returned from it.
+        let lock = parse_lock(PROJECT_A);
+        let export = std::thread::spawn(move || {
+            let target = TestTarget::new(&lock, &["project-a"]);
+            builder(&target).build().unwrap()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(main_component(&export).as_deref(), Some("project-a"));
+    }
+
+    #[test]
+    fn large_lock() {
+        // The project depends on every package, and each package on the next, such that the export
+        // has both a long dependency list and a deep dependency chain.
+        const PACKAGES: usize = 2000;
+        let mut lock = String::from(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+"#,
+        );
+        for index in 0..PACKAGES {
+            writeln!(
+                lock,
+                "\n[[package]]\nname = \"package-{index:04}\"\nversion = \"1.0.0\"\nsource = {{ registry = \"https://pypi.org/simple\" }}"
+            )
+            .unwrap();
+            if index + 1 < PACKAGES {
+                writeln!(
+                    lock,
+                    "dependencies = [\n    {{ name = \"package-{:04}\" }},\n]",
+                    index + 1
+                )
+                .unwrap();
+            }
+        }
+        lock.push_str("\n[[package]]\nname = \"project\"\nversion = \"0.1.0\"\nsource = { editable = \".\" }\ndependencies = [\n");
+        for index in 0..PACKAGES {
+            writeln!(lock, "    {{ name = \"package-{index:04}\" }},").unwrap();
+        }
+        lock.push_str("]\n");
+
+        let export = export(&lock, "project");
+        let bom = export.bom();
+        assert_eq!(bom.components.as_ref().unwrap().0.len(), PACKAGES);
+        let dependencies = &bom.dependencies.as_ref().unwrap().0;
+        assert_eq!(dependencies.len(), PACKAGES + 1);
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(|dependency| dependency.dependencies.len())
+                .sum::<usize>(),
+            2 * PACKAGES - 1
+        );
+    }
+
+    /// Create a builder whose output is stable between exports, i.e., without a timestamp or the
+    /// version of uv, for use in snapshots.
+    fn snapshot_builder<Target>(target: &Target) -> SbomExportBuilder<'_, Target> {
+        builder(target)
+            .with_timestamp(SbomTimestamp::Omit)
+            .with_tool_version(false)
+    }
+
+    /// Serialize the SBOM for use in snapshots.
+    ///
+    /// Without a timestamp, the serial number is derived from the contents of the SBOM, so it's
+    /// stable between exports.
+    fn snapshot(export: SbomExport) -> String {
+        let mut output = Vec::new();
+        export.output_as_json_v1_5(&mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn snapshot_project() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let export = snapshot_builder(&target).build().unwrap();
+        insta::assert_snapshot!(snapshot(export), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:762770cd-3e8f-5b7e-8587-3ba5bd960300",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-a-1@0.1.0",
+              "name": "project-a",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "idna-2@3.6",
+              "name": "idna",
+              "version": "3.6",
+              "purl": "pkg:pypi/idna@3.6",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            },
+            {
+              "type": "library",
+              "bom-ref": "urllib3-3@2.2.0",
+              "name": "urllib3",
+              "version": "2.2.0",
+              "purl": "pkg:pypi/urllib3@2.2.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "idna-2@3.6",
+              "dependsOn": []
+            },
+            {
+              "ref": "project-a-1@0.1.0",
+              "dependsOn": [
+                "idna-2@3.6",
+                "urllib3-3@2.2.0"
+              ]
+            },
+            {
+              "ref": "urllib3-3@2.2.0",
+              "dependsOn": []
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "idna-2@3.6",
+                "project-a-1@0.1.0",
+                "urllib3-3@2.2.0"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_pruned() {
+        let lock = parse_lock(PROJECT_A);
+        let target = TestTarget::new(&lock, &["project-a"]);
+        let prune = [PackageName::from_str("urllib3").unwrap()];
+        let export = snapshot_builder(&target)
+            .with_prune(&prune)
+            .build()
+            .unwrap();
+        insta::assert_snapshot!(snapshot(export), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:b56772b6-c08c-57ef-bdfd-33ef3cff78f2",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-a-1@0.1.0",
+              "name": "project-a",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "idna-2@3.6",
+              "name": "idna",
+              "version": "3.6",
+              "purl": "pkg:pypi/idna@3.6",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "idna-2@3.6",
+              "dependsOn": []
+            },
+            {
+              "ref": "project-a-1@0.1.0",
+              "dependsOn": [
+                "idna-2@3.6"
+              ]
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "idna-2@3.6"
+              ]
+            },
+            {
+              "aggregate": "incomplete_third_party_only",
+              "dependencies": [
+                "project-a-1@0.1.0"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_workspace() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[manifest]
+members = [
+    "child",
+    "utils",
+]
+
+[[package]]
+name = "child"
+version = "0.1.0"
+source = { editable = "packages/child" }
+dependencies = [
+    { name = "utils" },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "utils"
+version = "0.1.0"
+source = { editable = "packages/utils" }
+dependencies = [
+    { name = "idna" },
+]
+"#,
+        );
+        let target = LockTarget::new(&lock, Path::new(""));
+        let export = snapshot_builder(&target)
+            .with_all_packages(true)
+            .build()
+            .unwrap();
+        insta::assert_snapshot!(snapshot(export), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:bab6e5fa-5ad3-5b53-b0c8-7ee9024b170e",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "uv-workspace-4",
+              "name": "uv-workspace",
+              "properties": [
+                {
+                  "name": "uv:package:is_synthetic_root",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "child-1@0.1.0",
+              "name": "child",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:workspace:path",
+                  "value": "packages/child"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            {
+              "type": "library",
+              "bom-ref": "idna-2@3.6",
+              "name": "idna",
+              "version": "3.6",
+              "purl": "pkg:pypi/idna@3.6",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            },
+            {
+              "type": "library",
+              "bom-ref": "utils-3@0.1.0",
+              "name": "utils",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:workspace:path",
+                  "value": "packages/utils"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "child-1@0.1.0",
+              "dependsOn": [
+                "utils-3@0.1.0"
+              ]
+            },
+            {
+              "ref": "idna-2@3.6",
+              "dependsOn": []
+            },
+            {
+              "ref": "utils-3@0.1.0",
+              "dependsOn": [
+                "idna-2@3.6"
+              ]
+            },
+            {
+              "ref": "uv-workspace-4",
+              "dependsOn": [
+                "child-1@0.1.0",
+                "utils-3@0.1.0"
+              ]
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "child-1@0.1.0",
+                "idna-2@3.6",
+                "utils-3@0.1.0",
+                "uv-workspace-4"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_git_dependency() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "2.2.0"
+source = { git = "https://github.com/urllib3/urllib3?rev=2.2.0#04df048cf4b1c3790c56e26c659db764aad62d6f" }
+"#,
+        );
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = snapshot_builder(&target).build().unwrap();
+        insta::assert_snapshot!(snapshot(export), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:65a66ed9-d46e-591c-9d68-3ee2724ed350",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-1@0.1.0",
+              "name": "project",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "urllib3-2@2.2.0",
+              "group": "github.com",
+              "name": "urllib3",
+              "version": "2.2.0",
+              "purl": "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "git"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:git:commit",
+                  "value": "04df048cf4b1c3790c56e26c659db764aad62d6f"
+                },
+                {
+                  "name": "uv:git:reference",
+                  "value": "2.2.0"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "project-1@0.1.0",
+              "dependsOn": [
+                "urllib3-2@2.2.0"
+              ]
+            },
+            {
+              "ref": "urllib3-2@2.2.0",
+              "dependsOn": []
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "project-1@0.1.0",
+                "urllib3-2@2.2.0"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_path_dependency() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "dependency"
+version = "0.2.0"
+source = { directory = "../dependency" }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "dependency" },
+]
+"#,
+        );
+        let target = TestTarget::new(&lock, &["project"]);
+        let export = snapshot_builder(&target).build().unwrap();
+        insta::assert_snapshot!(snapshot(export), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:99ab4383-d3f2-5e30-90ac-aa39f4cacfba",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-1@0.1.0",
+              "name": "project",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "dependency-2@0.2.0",
+              "name": "dependency",
+              "version": "0.2.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "directory"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "dependency-2@0.2.0",
+              "dependsOn": []
+            },
+            {
+              "ref": "project-1@0.1.0",
+              "dependsOn": [
+                "dependency-2@0.2.0"
+              ]
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "dependency-2@0.2.0",
+                "project-1@0.1.0"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn snapshot_hashes() {
+        let lock = parse_lock(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+    { name = "idna" },
+]
+"#,
+        );
+        let target = TestTarget::new(&lock, &["project"]);
+        let hashed = snapshot_builder(&target).with_hashes(true).build().unwrap();
+        insta::assert_snapshot!(snapshot(hashed), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:5743bbb1-0b1c-56f5-9d22-a5c68aca59ce",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-1@0.1.0",
+              "name": "project",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "idna-2@3.6",
+              "name": "idna",
+              "version": "3.6",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                },
+                {
+                  "alg": "SHA-256",
+                  "content": "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                }
+              ],
+              "purl": "pkg:pypi/idna@3.6",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz",
+                  "hashes": [
+                    {
+                      "alg": "SHA-256",
+                      "content": "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                    }
+                  ]
+                },
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+                  "hashes": [
+                    {
+                      "alg": "SHA-256",
+                      "content": "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                    }
+                  ]
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:artifact:idna-3.6.tar.gz",
+                  "value": "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                },
+                {
+                  "name": "uv:artifact:idna-3.6-py3-none-any.whl",
+                  "value": "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                }
+              ],
+              "evidence": {
+                "identity": {
+                  "field": "purl",
+                  "confidence": 1.0,
+                  "methods": [
+                    {
+                      "technique": "hash-comparison",
+                      "confidence": 1.0
+                    }
+                  ],
+                  "tools": [
+                    "uv"
+                  ]
+                }
+              }
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "idna-2@3.6",
+              "dependsOn": []
+            },
+            {
+              "ref": "project-1@0.1.0",
+              "dependsOn": [
+                "idna-2@3.6"
+              ]
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "idna-2@3.6",
+                "project-1@0.1.0"
+              ]
+            }
+          ]
+        }
+        "#);
+
+        // Without hashes, neither the component hashes nor the distributions are recorded.
+        let unhashed = snapshot_builder(&target).build().unwrap();
+        insta::assert_snapshot!(snapshot(unhashed), @r#"
+        {
+          "bomFormat": "CycloneDX",
+          "specVersion": "1.5",
+          "version": 1,
+          "serialNumber": "urn:uuid:aa01433c-d729-5d52-9868-ed9e4d820f60",
+          "metadata": {
+            "tools": {
+              "components": [
+                {
+                  "type": "application",
+                  "bom-ref": "uv",
+                  "supplier": {
+                    "name": "Astral Software Inc."
+                  },
+                  "name": "uv"
+                }
+              ]
+            },
+            "component": {
+              "type": "library",
+              "bom-ref": "project-1@0.1.0",
+              "name": "project",
+              "version": "0.1.0",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "editable"
+                },
+                {
+                  "name": "uv:package:is_project_root",
+                  "value": "true"
+                },
+                {
+                  "name": "uv:package:is_editable",
+                  "value": "true"
+                }
+              ]
+            },
+            "lifecycles": [
+              {
+                "phase": "build"
+              }
+            ]
+          },
+          "components": [
+            {
+              "type": "library",
+              "bom-ref": "idna-2@3.6",
+              "name": "idna",
+              "version": "3.6",
+              "purl": "pkg:pypi/idna@3.6",
+              "properties": [
+                {
+                  "name": "uv:package:source_type",
+                  "value": "registry"
+                },
+                {
+                  "name": "uv:package:is_direct",
+                  "value": "true"
+                }
+              ]
+            }
+          ],
+          "dependencies": [
+            {
+              "ref": "idna-2@3.6",
+              "dependsOn": []
+            },
+            {
+              "ref": "project-1@0.1.0",
+              "dependsOn": [
+                "idna-2@3.6"
+              ]
+            }
+          ],
+          "compositions": [
+            {
+              "aggregate": "complete",
+              "dependencies": [
+                "idna-2@3.6",
+                "project-1@0.1.0"
+              ]
+            }
+          ]
+        }
+        "#);
+    }
 }
@@ -588,16 +588,25 @@ impl TestContext {
             "[SERIAL_NUMBER]".to_string(),
         ));
         self.filters.push((
-            r#""timestamp": "[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}\.[0-9]+Z""#
+            r#""timestamp": "[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?Z""#
                 .to_string(),
             r#""timestamp": "[TIMESTAMP]""#.to_string(),
         ));
         self.filters.push((
-            r#""name": "uv",\s*"version": "\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?""#
+            r#"("name": "uv",\s*"version": )"\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?""#
                 .to_string(),
-            r#""name": "uv",
-        "version": "[VERSION]""#
+            r#"$1"[VERSION]""#.to_string(),
+        ));
+        // The commit is only recorded when uv is built from a Git checkout, so drop the property
+        // entirely, whether or not it's the only one.
+        self.filters.push((
+            r#"\s*"properties": \[\s*\{\s*"name": "uv:tool:commit",\s*"value": "[0-9a-f]{40}"\s*\}\s*\],"#
                 .to_string(),
+            String::new(),
+        ));
+        self.filters.push((
+            r#",\s*\{\s*"name": "uv:tool:commit",\s*"value": "[0-9a-f]{40}"\s*\}"#.to_string(),
+            String::new(),
         ));
         self
     }
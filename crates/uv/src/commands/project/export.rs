@@ -301,11 +301,7 @@ pub(crate) async fn export(
         return Err(anyhow!(
             "`pyproject.toml` is not a supported output format for `{}` (supported formats: {})",
             "uv export".green(),
-            ExportFormat::value_variants()
-                .iter()
-                .filter_map(clap::ValueEnum::to_possible_value)
-                .map(|value| value.get_name().to_string())
-                .join(", ")
+            supported_formats()
         ));
     }
 
@@ -313,24 +309,10 @@ pub(crate) async fn export(
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
 
     // Determine the output format.
-    let format = format.unwrap_or_else(|| {
-        if output_file
-            .as_deref()
-            .and_then(Path::extension)
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("txt"))
-        {
-            ExportFormat::RequirementsTxt
-        } else if output_file
-            .as_deref()
-            .and_then(Path::file_name)
-            .and_then(OsStr::to_str)
-            .is_some_and(is_pylock_toml)
-        {
-            ExportFormat::PylockToml
-        } else {
-            ExportFormat::RequirementsTxt
-        }
-    });
+    let format = match format {
+        Some(format) => format,
+        None => infer_format(output_file.as_deref())?,
+    };
 
     // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
     if !matches!(format, ExportFormat::CycloneDX1_5) {
@@ -398,16 +380,20 @@ pub(crate) async fn export(
             write!(writer, "{}", export.to_toml()?)?;
         }
         ExportFormat::CycloneDX1_5 => {
-            let export = cyclonedx_json::from_lock(
-                &target,
-                &prune,
-                &extras,
-                &groups,
-                include_annotations,
-                &install_options,
-                preview,
-                all_packages,
-            )?;
+            let mut export = cyclonedx_json::SbomExportBuilder::new(&target)
+                .with_prune(&prune)
+                .with_extras(&extras)
+                .with_groups(&groups)
+                .with_annotate(include_annotations)
+                .with_editable(editable)
+                .with_install_options(&install_options)
+                .with_preview(preview)
+                .with_all_packages(all_packages)
+                .build()?;
+
+            if let Some(commit) = uv_cli::version::uv_self_version().commit_hash() {
+                export.set_tool_commit(commit);
+            }
 
             export.output_as_json_v1_5(&mut writer)?;
         }
@@ -468,3 +454,50 @@ fn cmd() -> String {
         .join(" ");
     format!("uv {args}")
 }
+
+/// Infer the export format from the name of the output file, defaulting to `requirements.txt`.
+fn infer_format(output_file: Option<&Path>) -> Result<ExportFormat> {
+    let Some(file_name) = output_file
+        .and_then(Path::file_name)
+        .and_then(OsStr::to_str)
+    else {
+        return Ok(ExportFormat::RequirementsTxt);
+    };
+
+    if is_pylock_toml(file_name) {
+        return Ok(ExportFormat::PylockToml);
+    }
+
+    // Reject the SBOM formats that aren't supported, rather than silently writing
+    // `requirements.txt` to a file that claims to be an SBOM.
+    let file_name = file_name.to_ascii_lowercase();
+    if let Some(extension) = [".spdx.json", ".spdx", ".xml"]
+        .into_iter()
+        .find(|extension| file_name.ends_with(extension))
+    {
+        return Err(anyhow!(
+            "`{extension}` is not a supported output format for `{}` (supported formats: {}); pass `{}` to select a format explicitly",
+            "uv export".green(),
+            supported_formats(),
+            "--format".green()
+        ));
+    }
+
+    if Path::new(&file_name)
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        return Ok(ExportFormat::CycloneDX1_5);
+    }
+
+    Ok(ExportFormat::RequirementsTxt)
+}
+
+/// The names of the supported export formats, e.g., for use in error messages.
+fn supported_formats() -> String {
+    ExportFormat::value_variants()
+        .iter()
+        .filter_map(clap::ValueEnum::to_possible_value)
+        .map(|value| value.get_name().to_string())
+        .join(", ")
+}
@@ -4554,6 +4554,42 @@ fn pep_751_infer_output_format() -> Result<()> {
     error: `pyproject.toml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5)
     ");
 
+    // A JSON output file is exported as a CycloneDX SBOM...
+    context
+        .export()
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("--quiet")
+        .arg("-o")
+        .arg("sbom.json")
+        .assert()
+        .success();
+    let sbom: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("sbom.json"),
+    )?)?;
+    assert_eq!(sbom["bomFormat"], "CycloneDX");
+
+    // ...while other SBOM formats are rejected.
+    uv_snapshot!(context.filters(), context.export().arg("-o").arg("sbom.spdx.json"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: `.spdx.json` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5); pass `--format` to select a format explicitly
+    ");
+
+    uv_snapshot!(context.filters(), context.export().arg("-o").arg("sbom.xml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: `.xml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5); pass `--format` to select a format explicitly
+    ");
+
     Ok(())
 }
 
@@ -4971,25 +5007,50 @@ fn cyclonedx_export_basic() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -4997,7 +5058,22 @@ fn cyclonedx_export_basic() -> Result<()> {
           "bom-ref": "urllib3-2@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5011,6 +5087,15 @@ fn cyclonedx_export_basic() -> Result<()> {
           "ref": "urllib3-2@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "urllib3-2@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5053,25 +5138,50 @@ fn cyclonedx_export_direct_url() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5079,7 +5189,18 @@ fn cyclonedx_export_direct_url() -> Result<()> {
           "bom-ref": "idna-2@3.6",
           "name": "idna",
           "version": "3.6",
-          "purl": "pkg:pypi/idna@3.6?download_url=https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl"
+          "description": "via project",
+          "purl": "pkg:pypi/idna@3.6?download_url=https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "url"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5093,6 +5214,15 @@ fn cyclonedx_export_direct_url() -> Result<()> {
             "idna-2@3.6"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "idna-2@3.6",
+            "project-1@0.1.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5136,33 +5266,78 @@ fn cyclonedx_export_git_dependency() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
           "type": "library",
           "bom-ref": "urllib3-2@2.2.0",
+          "group": "github.com",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3.git%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3.git%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "git"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:git:commit",
+              "value": "04df048cf4b1c3790c56e26c659db764aad62d6f"
+            },
+            {
+              "name": "uv:git:reference",
+              "value": "2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5176,6 +5351,15 @@ fn cyclonedx_export_git_dependency() -> Result<()> {
           "ref": "urllib3-2@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "urllib3-2@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5218,25 +5402,50 @@ fn cyclonedx_export_no_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "standalone-project-1@1.0.0",
           "name": "standalone-project",
           "version": "1.0.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [],
       "dependencies": [
@@ -5244,6 +5453,14 @@ fn cyclonedx_export_no_dependencies() -> Result<()> {
           "ref": "standalone-project-1@1.0.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "standalone-project-1@1.0.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5291,25 +5508,50 @@ fn cyclonedx_export_mixed_source_types() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "mixed-project-1@0.1.0",
           "name": "mixed-project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5317,21 +5559,67 @@ fn cyclonedx_export_mixed_source_types() -> Result<()> {
           "bom-ref": "idna-2@3.6",
           "name": "idna",
           "version": "3.6",
-          "purl": "pkg:pypi/idna@3.6?download_url=https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl"
+          "description": "via mixed-project",
+          "purl": "pkg:pypi/idna@3.6?download_url=https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "url"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via mixed-project",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-4@2.2.0",
+          "group": "github.com",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3.git%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f"
+          "description": "via mixed-project",
+          "purl": "pkg:pypi/urllib3@2.2.0?vcs_url=https://github.com/urllib3/urllib3.git%3Frev%3D2.2.0%2304df048cf4b1c3790c56e26c659db764aad62d6f",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "git"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:git:commit",
+              "value": "04df048cf4b1c3790c56e26c659db764aad62d6f"
+            },
+            {
+              "name": "uv:git:reference",
+              "value": "2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5355,6 +5643,17 @@ fn cyclonedx_export_mixed_source_types() -> Result<()> {
           "ref": "urllib3-4@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "idna-2@3.6",
+            "iniconfig-3@2.0.0",
+            "mixed-project-1@0.1.0",
+            "urllib3-4@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5401,25 +5700,50 @@ fn cyclonedx_export_project_extra() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5427,7 +5751,22 @@ fn cyclonedx_export_project_extra() -> Result<()> {
           "bom-ref": "typing-extensions-2@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5441,6 +5780,15 @@ fn cyclonedx_export_project_extra() -> Result<()> {
           "ref": "typing-extensions-2@4.10.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "typing-extensions-2@4.10.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5487,25 +5835,50 @@ fn cyclonedx_export_project_extra_with_optional_flag() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5513,21 +5886,66 @@ fn cyclonedx_export_project_extra_with_optional_flag() -> Result<()> {
           "bom-ref": "iniconfig-2@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via project",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "typing-extensions-3@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-4@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5551,6 +5969,17 @@ fn cyclonedx_export_project_extra_with_optional_flag() -> Result<()> {
           "ref": "urllib3-4@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "iniconfig-2@2.0.0",
+            "project-1@0.1.0",
+            "typing-extensions-3@4.10.0",
+            "urllib3-4@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5630,25 +6059,50 @@ fn cyclonedx_export_with_workspace_member() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5656,10 +6110,23 @@ fn cyclonedx_export_with_workspace_member() -> Result<()> {
           "bom-ref": "child1-2@0.1.0",
           "name": "child1",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child1"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -5668,10 +6135,23 @@ fn cyclonedx_export_with_workspace_member() -> Result<()> {
           "bom-ref": "child2-3@0.2.9",
           "name": "child2",
           "version": "0.2.9",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "packages/child2"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -5680,14 +6160,36 @@ fn cyclonedx_export_with_workspace_member() -> Result<()> {
           "bom-ref": "iniconfig-4@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child1",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-5@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5717,6 +6219,18 @@ fn cyclonedx_export_with_workspace_member() -> Result<()> {
           "ref": "urllib3-5@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child1-2@0.1.0",
+            "child2-3@0.2.9",
+            "iniconfig-4@2.0.0",
+            "project-1@0.1.0",
+            "urllib3-5@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5780,25 +6294,50 @@ fn cyclonedx_export_workspace_non_root() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "child-1@0.1.0",
           "name": "child",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5806,7 +6345,22 @@ fn cyclonedx_export_workspace_non_root() -> Result<()> {
           "bom-ref": "iniconfig-2@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5820,6 +6374,15 @@ fn cyclonedx_export_workspace_non_root() -> Result<()> {
           "ref": "iniconfig-2@2.0.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-1@0.1.0",
+            "iniconfig-2@2.0.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5887,25 +6450,50 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5913,10 +6501,23 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "bom-ref": "child-2@0.1.0",
           "name": "child",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -5925,7 +6526,14 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "bom-ref": "typing-extensions-3@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via child",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -5945,6 +6553,16 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "ref": "typing-extensions-3@4.10.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "project-1@0.1.0",
+            "typing-extensions-3@4.10.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -5963,25 +6581,50 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -5989,10 +6632,23 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "bom-ref": "child-2@0.1.0",
           "name": "child",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -6001,21 +6657,58 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via project",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "typing-extensions-4@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via child",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-5@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -6045,6 +6738,18 @@ fn cyclonedx_export_workspace_with_extras() -> Result<()> {
           "ref": "urllib3-5@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "iniconfig-3@2.0.0",
+            "project-1@0.1.0",
+            "typing-extensions-4@4.10.0",
+            "urllib3-5@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6122,13 +6827,19 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-5",
@@ -6139,7 +6850,18 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6147,10 +6869,23 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
           "bom-ref": "child-2@0.1.0",
           "name": "child",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -6159,14 +6894,44 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-4@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         },
         {
           "type": "library",
@@ -6174,9 +6939,17 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         }
@@ -6210,6 +6983,18 @@ fn cyclonedx_export_workspace_frozen() -> Result<()> {
             "project-1@0.1.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "iniconfig-3@2.0.0",
+            "project-1@0.1.0",
+            "project-5",
+            "urllib3-4@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6284,13 +7069,19 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-7",
@@ -6301,7 +7092,18 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6310,9 +7112,17 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
           "name": "child1",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child1"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         },
@@ -6322,9 +7132,17 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
           "name": "child2",
           "version": "0.2.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child2"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         },
@@ -6333,21 +7151,66 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
           "bom-ref": "iniconfig-4@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child1",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "sniffio-5@1.3.1",
           "name": "sniffio",
           "version": "1.3.1",
-          "purl": "pkg:pypi/sniffio@1.3.1"
+          "description": "via child2",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==1.3.1"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-6@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         },
         {
           "type": "library",
@@ -6355,9 +7218,17 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         }
@@ -6401,6 +7272,20 @@ fn cyclonedx_export_workspace_all_packages() -> Result<()> {
             "project-1@0.1.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child1-2@0.1.0",
+            "child2-3@0.2.0",
+            "iniconfig-4@2.0.0",
+            "project-1@0.1.0",
+            "project-7",
+            "sniffio-5@1.3.1",
+            "urllib3-6@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6443,14 +7328,20 @@ fn cyclonedx_export_all_packages_non_workspace_root_dependency() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
-        "component": {
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
+        "component": {
           "type": "library",
           "bom-ref": "my-project-3",
           "name": "my-project",
@@ -6460,7 +7351,18 @@ fn cyclonedx_export_all_packages_non_workspace_root_dependency() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6468,7 +7370,22 @@ fn cyclonedx_export_all_packages_non_workspace_root_dependency() -> Result<()> {
           "bom-ref": "urllib3-2@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via my-project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         },
         {
           "type": "library",
@@ -6476,9 +7393,17 @@ fn cyclonedx_export_all_packages_non_workspace_root_dependency() -> Result<()> {
           "name": "my-project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         }
@@ -6500,6 +7425,16 @@ fn cyclonedx_export_all_packages_non_workspace_root_dependency() -> Result<()> {
             "my-project-1@0.1.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "my-project-1@0.1.0",
+            "my-project-3",
+            "urllib3-2@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6582,25 +7517,50 @@ fn cyclonedx_export_workspace_mixed_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6608,10 +7568,23 @@ fn cyclonedx_export_workspace_mixed_dependencies() -> Result<()> {
           "bom-ref": "child1-2@0.1.0",
           "name": "child1",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child1"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -6620,10 +7593,19 @@ fn cyclonedx_export_workspace_mixed_dependencies() -> Result<()> {
           "bom-ref": "child2-3@0.2.0",
           "name": "child2",
           "version": "0.2.0",
+          "description": "via child1",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child2"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         },
@@ -6632,21 +7614,50 @@ fn cyclonedx_export_workspace_mixed_dependencies() -> Result<()> {
           "bom-ref": "iniconfig-4@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child1",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "sniffio-5@1.3.1",
           "name": "sniffio",
           "version": "1.3.1",
-          "purl": "pkg:pypi/sniffio@1.3.1"
+          "description": "via child2",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-6@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -6682,6 +7693,19 @@ fn cyclonedx_export_workspace_mixed_dependencies() -> Result<()> {
           "ref": "urllib3-6@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child1-2@0.1.0",
+            "child2-3@0.2.0",
+            "iniconfig-4@2.0.0",
+            "project-1@0.1.0",
+            "sniffio-5@1.3.1",
+            "urllib3-6@2.2.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6727,25 +7751,50 @@ fn cyclonedx_export_dependency_marker() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6753,18 +7802,46 @@ fn cyclonedx_export_dependency_marker() -> Result<()> {
           "bom-ref": "iniconfig-2@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via project",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-3@2.2.1",
           "name": "urllib3",
           "version": "2.2.1",
+          "description": "via project",
           "purl": "pkg:pypi/urllib3@2.2.1",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
             {
               "name": "uv:package:marker",
               "value": "sys_platform == 'darwin'"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.1"
             }
           ]
         }
@@ -6785,6 +7862,16 @@ fn cyclonedx_export_dependency_marker() -> Result<()> {
           "ref": "urllib3-3@2.2.1",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "iniconfig-2@2.0.0",
+            "project-1@0.1.0",
+            "urllib3-3@2.2.1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6830,25 +7917,50 @@ fn cyclonedx_export_multiple_dependency_markers() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6856,8 +7968,13 @@ fn cyclonedx_export_multiple_dependency_markers() -> Result<()> {
           "bom-ref": "cffi-2@1.16.0",
           "name": "cffi",
           "version": "1.16.0",
+          "description": "via cryptography",
           "purl": "pkg:pypi/cffi@1.16.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "(python_full_version >= '3.12' and platform_python_implementation != 'PyPy') or (platform_python_implementation != 'PyPy' and sys_platform == 'win32')"
@@ -6869,11 +7986,24 @@ fn cyclonedx_export_multiple_dependency_markers() -> Result<()> {
           "bom-ref": "cryptography-3@42.0.5",
           "name": "cryptography",
           "version": "42.0.5",
+          "description": "via project",
           "purl": "pkg:pypi/cryptography@42.0.5",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
             {
               "name": "uv:package:marker",
               "value": "python_full_version >= '3.12' or sys_platform == 'win32'"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==42.0.5"
             }
           ]
         },
@@ -6882,8 +8012,13 @@ fn cyclonedx_export_multiple_dependency_markers() -> Result<()> {
           "bom-ref": "pycparser-4@2.21",
           "name": "pycparser",
           "version": "2.21",
+          "description": "via cffi",
           "purl": "pkg:pypi/pycparser@2.21",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "(python_full_version >= '3.12' and platform_python_implementation != 'PyPy') or (platform_python_implementation != 'PyPy' and sys_platform == 'win32')"
@@ -6914,6 +8049,17 @@ fn cyclonedx_export_multiple_dependency_markers() -> Result<()> {
           "ref": "pycparser-4@2.21",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "cffi-2@1.16.0",
+            "cryptography-3@42.0.5",
+            "project-1@0.1.0",
+            "pycparser-4@2.21"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -6956,25 +8102,50 @@ fn cyclonedx_export_dependency_extra() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -6982,15 +8153,27 @@ fn cyclonedx_export_dependency_extra() -> Result<()> {
           "bom-ref": "bcrypt-2@4.1.2",
           "name": "bcrypt",
           "version": "4.1.2",
-          "purl": "pkg:pypi/bcrypt@4.1.2"
+          "description": "via cryptography",
+          "purl": "pkg:pypi/bcrypt@4.1.2",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "cffi-3@1.16.0",
           "name": "cffi",
           "version": "1.16.0",
+          "description": "via cryptography",
           "purl": "pkg:pypi/cffi@1.16.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "platform_python_implementation != 'PyPy'"
@@ -7002,15 +8185,35 @@ fn cyclonedx_export_dependency_extra() -> Result<()> {
           "bom-ref": "cryptography-4@42.0.5",
           "name": "cryptography",
           "version": "42.0.5",
-          "purl": "pkg:pypi/cryptography@42.0.5"
+          "description": "via project",
+          "purl": "pkg:pypi/cryptography@42.0.5",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==42.0.5"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "pycparser-5@2.21",
           "name": "pycparser",
           "version": "2.21",
+          "description": "via cffi",
           "purl": "pkg:pypi/pycparser@2.21",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "platform_python_implementation != 'PyPy'"
@@ -7046,6 +8249,18 @@ fn cyclonedx_export_dependency_extra() -> Result<()> {
           "ref": "pycparser-5@2.21",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "bcrypt-2@4.1.2",
+            "cffi-3@1.16.0",
+            "cryptography-4@42.0.5",
+            "project-1@0.1.0",
+            "pycparser-5@2.21"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7093,25 +8308,46 @@ fn cyclonedx_export_prune() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "virtual"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7119,8 +8355,13 @@ fn cyclonedx_export_prune() -> Result<()> {
           "bom-ref": "cffi-2@1.16.0",
           "name": "cffi",
           "version": "1.16.0",
+          "description": "via pyzmq",
           "purl": "pkg:pypi/cffi@1.16.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "implementation_name == 'pypy'"
@@ -7132,15 +8373,35 @@ fn cyclonedx_export_prune() -> Result<()> {
           "bom-ref": "jupyter-client-3@8.6.1",
           "name": "jupyter-client",
           "version": "8.6.1",
-          "purl": "pkg:pypi/jupyter-client@8.6.1"
+          "description": "via project",
+          "purl": "pkg:pypi/jupyter-client@8.6.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==8.6.1"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "pycparser-4@2.21",
           "name": "pycparser",
           "version": "2.21",
+          "description": "via cffi",
           "purl": "pkg:pypi/pycparser@2.21",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
             {
               "name": "uv:package:marker",
               "value": "implementation_name == 'pypy'"
@@ -7152,35 +8413,70 @@ fn cyclonedx_export_prune() -> Result<()> {
           "bom-ref": "python-dateutil-5@2.9.0.post0",
           "name": "python-dateutil",
           "version": "2.9.0.post0",
-          "purl": "pkg:pypi/python-dateutil@2.9.0.post0"
+          "description": "via jupyter-client",
+          "purl": "pkg:pypi/python-dateutil@2.9.0.post0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "pyzmq-6@25.1.2",
           "name": "pyzmq",
           "version": "25.1.2",
-          "purl": "pkg:pypi/pyzmq@25.1.2"
+          "description": "via jupyter-client",
+          "purl": "pkg:pypi/pyzmq@25.1.2",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "six-7@1.16.0",
           "name": "six",
           "version": "1.16.0",
-          "purl": "pkg:pypi/six@1.16.0"
+          "description": "via python-dateutil",
+          "purl": "pkg:pypi/six@1.16.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
-          "type": "library",
+          "type": "framework",
           "bom-ref": "tornado-8@6.4",
           "name": "tornado",
           "version": "6.4",
-          "purl": "pkg:pypi/tornado@6.4"
+          "description": "via jupyter-client",
+          "purl": "pkg:pypi/tornado@6.4",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "traitlets-9@5.14.2",
           "name": "traitlets",
           "version": "5.14.2",
-          "purl": "pkg:pypi/traitlets@5.14.2"
+          "description": "via jupyter-client",
+          "purl": "pkg:pypi/traitlets@5.14.2",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7233,6 +8529,27 @@ fn cyclonedx_export_prune() -> Result<()> {
           "ref": "traitlets-9@5.14.2",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "cffi-2@1.16.0",
+            "project-1@0.1.0",
+            "pycparser-4@2.21",
+            "python-dateutil-5@2.9.0.post0",
+            "pyzmq-6@25.1.2",
+            "six-7@1.16.0",
+            "tornado-8@6.4",
+            "traitlets-9@5.14.2"
+          ]
+        },
+        {
+          "aggregate": "incomplete_third_party_only",
+          "dependencies": [
+            "jupyter-client-3@8.6.1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7278,25 +8595,46 @@ fn cyclonedx_export_group() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "virtual"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7304,14 +8642,47 @@ fn cyclonedx_export_group() -> Result<()> {
           "bom-ref": "sniffio-2@1.3.1",
           "name": "sniffio",
           "version": "1.3.1",
-          "purl": "pkg:pypi/sniffio@1.3.1"
-        },
-        {
-          "type": "library",
-          "bom-ref": "typing-extensions-3@4.10.0",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "dev"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==1.3.1"
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "typing-extensions-3@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7330,6 +8701,16 @@ fn cyclonedx_export_group() -> Result<()> {
           "ref": "typing-extensions-3@4.10.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "sniffio-2@1.3.1",
+            "typing-extensions-3@4.10.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7349,25 +8730,46 @@ fn cyclonedx_export_group() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "virtual"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7375,7 +8777,25 @@ fn cyclonedx_export_group() -> Result<()> {
           "bom-ref": "iniconfig-2@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "bar"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.0.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7383,6 +8803,14 @@ fn cyclonedx_export_group() -> Result<()> {
           "ref": "iniconfig-2@2.0.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "iniconfig-2@2.0.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7402,25 +8830,46 @@ fn cyclonedx_export_group() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "virtual"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7428,14 +8877,47 @@ fn cyclonedx_export_group() -> Result<()> {
           "bom-ref": "sniffio-2@1.3.1",
           "name": "sniffio",
           "version": "1.3.1",
-          "purl": "pkg:pypi/sniffio@1.3.1"
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "dev"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==1.3.1"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "typing-extensions-3@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         },
         {
           "type": "library",
@@ -7444,9 +8926,25 @@ fn cyclonedx_export_group() -> Result<()> {
           "version": "2.2.1",
           "purl": "pkg:pypi/urllib3@2.2.1",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
             {
               "name": "uv:package:marker",
               "value": "sys_platform == 'darwin'"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "foo"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.1"
             }
           ]
         }
@@ -7472,6 +8970,17 @@ fn cyclonedx_export_group() -> Result<()> {
           "ref": "urllib3-4@2.2.1",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "sniffio-2@1.3.1",
+            "typing-extensions-3@4.10.0",
+            "urllib3-4@2.2.1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7511,13 +9020,19 @@ fn cyclonedx_export_non_project() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "uv-workspace-1",
@@ -7528,7 +9043,18 @@ fn cyclonedx_export_non_project() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [],
       "dependencies": [
@@ -7536,6 +9062,14 @@ fn cyclonedx_export_non_project() -> Result<()> {
           "ref": "uv-workspace-1",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "uv-workspace-1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7556,13 +9090,19 @@ fn cyclonedx_export_non_project() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "uv-workspace-2",
@@ -7573,7 +9113,18 @@ fn cyclonedx_export_non_project() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7581,7 +9132,25 @@ fn cyclonedx_export_non_project() -> Result<()> {
           "bom-ref": "urllib3-1@2.2.1",
           "name": "urllib3",
           "version": "2.2.1",
-          "purl": "pkg:pypi/urllib3@2.2.1"
+          "purl": "pkg:pypi/urllib3@2.2.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "url"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.1"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7593,6 +9162,15 @@ fn cyclonedx_export_non_project() -> Result<()> {
           "ref": "uv-workspace-2",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "urllib3-1@2.2.1",
+            "uv-workspace-2"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7658,25 +9236,50 @@ fn cyclonedx_export_no_emit() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7684,10 +9287,23 @@ fn cyclonedx_export_no_emit() -> Result<()> {
           "bom-ref": "child-2@0.1.0",
           "name": "child",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -7696,7 +9312,14 @@ fn cyclonedx_export_no_emit() -> Result<()> {
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7716,6 +9339,21 @@ fn cyclonedx_export_no_emit() -> Result<()> {
             "child-2@0.1.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "iniconfig-3@2.0.0"
+          ]
+        },
+        {
+          "aggregate": "incomplete_third_party_only",
+          "dependencies": [
+            "project-1@0.1.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7735,25 +9373,50 @@ fn cyclonedx_export_no_emit() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7761,10 +9424,23 @@ fn cyclonedx_export_no_emit() -> Result<()> {
           "bom-ref": "child-2@0.1.0",
           "name": "child",
           "version": "0.1.0",
+          "description": "via project",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -7773,14 +9449,32 @@ fn cyclonedx_export_no_emit() -> Result<()> {
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via child",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-4@2.2.0",
           "name": "urllib3",
           "version": "2.2.0",
-          "purl": "pkg:pypi/urllib3@2.2.0"
+          "description": "via project",
+          "purl": "pkg:pypi/urllib3@2.2.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7798,6 +9492,22 @@ fn cyclonedx_export_no_emit() -> Result<()> {
           "ref": "urllib3-4@2.2.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "iniconfig-3@2.0.0",
+            "urllib3-4@2.2.0"
+          ]
+        },
+        {
+          "aggregate": "incomplete_first_party_only",
+          "dependencies": [
+            "project-1@0.1.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -7858,39 +9568,82 @@ fn cyclonedx_export_relative_path() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
           "type": "library",
           "bom-ref": "dependency-2@0.1.0",
           "name": "dependency",
-          "version": "0.1.0"
+          "version": "0.1.0",
+          "description": "via project",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "directory"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "iniconfig-3@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via dependency",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -7910,8 +9663,18 @@ fn cyclonedx_export_relative_path() -> Result<()> {
             "dependency-2@0.1.0"
           ]
         }
-      ]
-    }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "dependency-2@0.1.0",
+            "iniconfig-3@2.0.0",
+            "project-1@0.1.0"
+          ]
+        }
+      ]
+    }
     ----- stderr -----
     Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
     Resolved 3 packages in [TIME]
@@ -7952,25 +9715,46 @@ fn cyclonedx_export_cyclic_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "virtual"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -7978,70 +9762,156 @@ fn cyclonedx_export_cyclic_dependencies() -> Result<()> {
           "bom-ref": "argparse-2@1.4.0",
           "name": "argparse",
           "version": "1.4.0",
-          "purl": "pkg:pypi/argparse@1.4.0"
+          "description": "via unittest2",
+          "purl": "pkg:pypi/argparse@1.4.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "extras-3@1.0.0",
           "name": "extras",
           "version": "1.0.0",
-          "purl": "pkg:pypi/extras@1.0.0"
+          "description": "via testtools",
+          "purl": "pkg:pypi/extras@1.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "fixtures-4@3.0.0",
           "name": "fixtures",
           "version": "3.0.0",
-          "purl": "pkg:pypi/fixtures@3.0.0"
+          "description": "via project, testtools",
+          "purl": "pkg:pypi/fixtures@3.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==3.0.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "linecache2-5@1.0.0",
           "name": "linecache2",
           "version": "1.0.0",
-          "purl": "pkg:pypi/linecache2@1.0.0"
+          "description": "via traceback2",
+          "purl": "pkg:pypi/linecache2@1.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "pbr-6@6.0.0",
           "name": "pbr",
           "version": "6.0.0",
-          "purl": "pkg:pypi/pbr@6.0.0"
+          "description": "via fixtures, testtools",
+          "purl": "pkg:pypi/pbr@6.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "python-mimeparse-7@1.6.0",
           "name": "python-mimeparse",
           "version": "1.6.0",
-          "purl": "pkg:pypi/python-mimeparse@1.6.0"
+          "description": "via testtools",
+          "purl": "pkg:pypi/python-mimeparse@1.6.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "six-8@1.16.0",
           "name": "six",
           "version": "1.16.0",
-          "purl": "pkg:pypi/six@1.16.0"
+          "description": "via fixtures, testtools, unittest2",
+          "purl": "pkg:pypi/six@1.16.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "testtools-9@2.3.0",
           "name": "testtools",
           "version": "2.3.0",
-          "purl": "pkg:pypi/testtools@2.3.0"
+          "description": "via fixtures, project",
+          "purl": "pkg:pypi/testtools@2.3.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.3.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "traceback2-10@1.4.0",
           "name": "traceback2",
           "version": "1.4.0",
-          "purl": "pkg:pypi/traceback2@1.4.0"
+          "description": "via testtools, unittest2",
+          "purl": "pkg:pypi/traceback2@1.4.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "unittest2-11@1.1.0",
           "name": "unittest2",
           "version": "1.1.0",
-          "purl": "pkg:pypi/unittest2@1.1.0"
+          "description": "via testtools",
+          "purl": "pkg:pypi/unittest2@1.1.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8110,6 +9980,24 @@ fn cyclonedx_export_cyclic_dependencies() -> Result<()> {
             "traceback2-10@1.4.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "argparse-2@1.4.0",
+            "extras-3@1.0.0",
+            "fixtures-4@3.0.0",
+            "linecache2-5@1.0.0",
+            "pbr-6@6.0.0",
+            "project-1@0.1.0",
+            "python-mimeparse-7@1.6.0",
+            "six-8@1.16.0",
+            "testtools-9@2.3.0",
+            "traceback2-10@1.4.0",
+            "unittest2-11@1.1.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8156,25 +10044,50 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -8182,14 +10095,47 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "bom-ref": "typing-extensions-2@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "urllib3-3@2.2.1",
           "name": "urllib3",
           "version": "2.2.1",
-          "purl": "pkg:pypi/urllib3@2.2.1"
+          "purl": "pkg:pypi/urllib3@2.2.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "dev"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.1"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8208,6 +10154,16 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "ref": "urllib3-3@2.2.1",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "typing-extensions-2@4.10.0",
+            "urllib3-3@2.2.1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8228,25 +10184,50 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -8254,7 +10235,22 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "bom-ref": "typing-extensions-2@4.10.0",
           "name": "typing-extensions",
           "version": "4.10.0",
-          "purl": "pkg:pypi/typing-extensions@4.10.0"
+          "description": "via project",
+          "purl": "pkg:pypi/typing-extensions@4.10.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==4.10.0"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8268,6 +10264,15 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "ref": "typing-extensions-2@4.10.0",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "project-1@0.1.0",
+            "typing-extensions-2@4.10.0"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8288,25 +10293,50 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -8314,7 +10344,25 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "bom-ref": "urllib3-2@2.2.1",
           "name": "urllib3",
           "version": "2.2.1",
-          "purl": "pkg:pypi/urllib3@2.2.1"
+          "purl": "pkg:pypi/urllib3@2.2.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:groups",
+              "value": "dev"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.2.1"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8322,6 +10370,14 @@ fn cyclonedx_export_dev_dependencies() -> Result<()> {
           "ref": "urllib3-2@2.2.1",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "urllib3-2@2.2.1"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8392,13 +10448,19 @@ fn cyclonedx_export_all_packages_conflicting_workspace_members() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-3",
@@ -8409,7 +10471,18 @@ fn cyclonedx_export_all_packages_conflicting_workspace_members() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -8418,9 +10491,17 @@ fn cyclonedx_export_all_packages_conflicting_workspace_members() -> Result<()> {
           "name": "child",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "child"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         },
@@ -8430,9 +10511,17 @@ fn cyclonedx_export_all_packages_conflicting_workspace_members() -> Result<()> {
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         }
@@ -8453,6 +10542,16 @@ fn cyclonedx_export_all_packages_conflicting_workspace_members() -> Result<()> {
             "project-1@0.1.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "child-2@0.1.0",
+            "project-1@0.1.0",
+            "project-3"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8525,115 +10624,240 @@ fn cyclonedx_export_alternative_registry() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "project-1@0.1.0",
           "name": "project",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:package:is_project_root",
               "value": "true"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2025-01-30T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
           "type": "library",
           "bom-ref": "filelock-2@3.13.1",
+          "group": "astral-sh.github.io",
           "name": "filelock",
           "version": "3.13.1",
-          "purl": "pkg:pypi/filelock@3.13.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/filelock@3.13.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "fsspec-3@2024.6.1",
+          "group": "astral-sh.github.io",
           "name": "fsspec",
           "version": "2024.6.1",
-          "purl": "pkg:pypi/fsspec@2024.6.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/fsspec@2024.6.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "jinja2-4@3.1.4",
+          "group": "astral-sh.github.io",
           "name": "jinja2",
           "version": "3.1.4",
-          "purl": "pkg:pypi/jinja2@3.1.4?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/jinja2@3.1.4?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "markupsafe-5@3.0.2",
+          "group": "astral-sh.github.io",
           "name": "markupsafe",
           "version": "3.0.2",
-          "purl": "pkg:pypi/markupsafe@3.0.2?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via jinja2",
+          "purl": "pkg:pypi/markupsafe@3.0.2?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "mpmath-6@1.3.0",
+          "group": "astral-sh.github.io",
           "name": "mpmath",
           "version": "1.3.0",
-          "purl": "pkg:pypi/mpmath@1.3.0?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via sympy",
+          "purl": "pkg:pypi/mpmath@1.3.0?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "networkx-7@3.3",
+          "group": "astral-sh.github.io",
           "name": "networkx",
           "version": "3.3",
-          "purl": "pkg:pypi/networkx@3.3?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/networkx@3.3?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "setuptools-8@70.2.0",
+          "group": "astral-sh.github.io",
           "name": "setuptools",
           "version": "70.2.0",
-          "purl": "pkg:pypi/setuptools@70.2.0?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/setuptools@70.2.0?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "sympy-9@1.13.1",
+          "group": "astral-sh.github.io",
           "name": "sympy",
           "version": "1.13.1",
-          "purl": "pkg:pypi/sympy@1.13.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/sympy@1.13.1?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "torch-10@2.6.0",
+          "group": "astral-sh.github.io",
           "name": "torch",
           "version": "2.6.0",
+          "description": "via project",
           "purl": "pkg:pypi/torch@2.6.0?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
             {
               "name": "uv:package:marker",
               "value": "sys_platform == 'darwin'"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.6.0"
             }
           ]
         },
         {
           "type": "library",
           "bom-ref": "torch-11@2.6.0+cpu",
+          "group": "astral-sh.github.io",
           "name": "torch",
           "version": "2.6.0+cpu",
+          "description": "via project",
           "purl": "pkg:pypi/torch@2.6.0%2Bcpu?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
             {
               "name": "uv:package:marker",
               "value": "sys_platform != 'darwin'"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==2.6.0"
             }
           ]
         },
         {
           "type": "library",
           "bom-ref": "typing-extensions-12@4.12.2",
+          "group": "astral-sh.github.io",
           "name": "typing-extensions",
           "version": "4.12.2",
-          "purl": "pkg:pypi/typing-extensions@4.12.2?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu"
+          "description": "via torch",
+          "purl": "pkg:pypi/typing-extensions@4.12.2?repository_url=https://astral-sh.github.io/pytorch-mirror/whl/cpu",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8708,6 +10932,25 @@ fn cyclonedx_export_alternative_registry() -> Result<()> {
           "ref": "typing-extensions-12@4.12.2",
           "dependsOn": []
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "filelock-2@3.13.1",
+            "fsspec-3@2024.6.1",
+            "jinja2-4@3.1.4",
+            "markupsafe-5@3.0.2",
+            "mpmath-6@1.3.0",
+            "networkx-7@3.3",
+            "project-1@0.1.0",
+            "setuptools-8@70.2.0",
+            "sympy-9@1.13.1",
+            "torch-10@2.6.0",
+            "torch-11@2.6.0+cpu",
+            "typing-extensions-12@4.12.2"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8746,13 +10989,19 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
       "serialNumber": "[SERIAL_NUMBER]",
       "metadata": {
         "timestamp": "[TIMESTAMP]",
-        "tools": [
-          {
-            "vendor": "Astral Software Inc.",
-            "name": "uv",
-            "version": "[VERSION]"
-          }
-        ],
+        "tools": {
+          "components": [
+            {
+              "type": "application",
+              "bom-ref": "uv",
+              "supplier": {
+                "name": "Astral Software Inc."
+              },
+              "name": "uv",
+              "version": "[VERSION]"
+            }
+          ]
+        },
         "component": {
           "type": "library",
           "bom-ref": "uv-workspace-8",
@@ -8763,7 +11012,18 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
               "value": "true"
             }
           ]
-        }
+        },
+        "properties": [
+          {
+            "name": "uv:lock:exclude_newer",
+            "value": "2024-03-25T00:00:00Z"
+          }
+        ],
+        "lifecycles": [
+          {
+            "phase": "build"
+          }
+        ]
       },
       "components": [
         {
@@ -8772,9 +11032,17 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
           "name": "albatross",
           "version": "0.1.0",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "packages/albatross"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
             }
           ]
         },
@@ -8783,17 +11051,45 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
           "bom-ref": "anyio-2@4.3.0",
           "name": "anyio",
           "version": "4.3.0",
-          "purl": "pkg:pypi/anyio@4.3.0"
+          "description": "via bird-feeder",
+          "purl": "pkg:pypi/anyio@4.3.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": ">=4.3.0,<5"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "bird-feeder-3@1.0.0",
           "name": "bird-feeder",
           "version": "1.0.0",
+          "description": "via albatross",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "packages/bird-feeder"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -8802,24 +11098,67 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
           "bom-ref": "idna-4@3.6",
           "name": "idna",
           "version": "3.6",
-          "purl": "pkg:pypi/idna@3.6"
+          "description": "via anyio, seeds",
+          "purl": "pkg:pypi/idna@3.6",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": "==3.6"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "iniconfig-5@2.0.0",
           "name": "iniconfig",
           "version": "2.0.0",
-          "purl": "pkg:pypi/iniconfig@2.0.0"
+          "description": "via albatross",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:requested_specifier",
+              "value": ">=2,<3"
+            }
+          ]
         },
         {
           "type": "library",
           "bom-ref": "seeds-6@1.0.0",
           "name": "seeds",
           "version": "1.0.0",
+          "description": "via bird-feeder",
           "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "editable"
+            },
             {
               "name": "uv:workspace:path",
               "value": "packages/seeds"
+            },
+            {
+              "name": "uv:package:is_editable",
+              "value": "true"
+            },
+            {
+              "name": "uv:package:is_direct",
+              "value": "true"
             }
           ]
         },
@@ -8828,7 +11167,14 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
           "bom-ref": "sniffio-7@1.3.1",
           "name": "sniffio",
           "version": "1.3.1",
-          "purl": "pkg:pypi/sniffio@1.3.1"
+          "description": "via anyio",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "properties": [
+            {
+              "name": "uv:package:source_type",
+              "value": "registry"
+            }
+          ]
         }
       ],
       "dependencies": [
@@ -8879,6 +11225,21 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
             "seeds-6@1.0.0"
           ]
         }
+      ],
+      "compositions": [
+        {
+          "aggregate": "complete",
+          "dependencies": [
+            "albatross-1@0.1.0",
+            "anyio-2@4.3.0",
+            "bird-feeder-3@1.0.0",
+            "idna-4@3.6",
+            "iniconfig-5@2.0.0",
+            "seeds-6@1.0.0",
+            "sniffio-7@1.3.1",
+            "uv-workspace-8"
+          ]
+        }
       ]
     }
     ----- stderr -----
@@ -8890,6 +11251,152 @@ fn cyclonedx_export_virtual_workspace_fixture() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn cyclonedx_export_no_annotate() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["urllib3==2.2.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("--no-annotate")
+        .arg("--quiet")
+        .arg("-o")
+        .arg("sbom.json")
+        .assert()
+        .success();
+    let sbom: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("sbom.json"),
+    )?)?;
+
+    // Without annotations, components aren't described by their dependents.
+    assert_eq!(sbom["components"][0]["name"], "urllib3");
+    assert!(sbom["components"][0].get("description").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn cyclonedx_export_no_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["urllib3==2.2.0"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("--no-editable")
+        .arg("--quiet")
+        .arg("-o")
+        .arg("sbom.json")
+        .assert()
+        .success();
+    let sbom: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("sbom.json"),
+    )?)?;
+
+    // The project is still sourced from the workspace, but isn't marked as editable.
+    assert_eq!(
+        sbom["metadata"]["component"]["properties"],
+        serde_json::json!([
+            { "name": "uv:package:source_type", "value": "editable" },
+            { "name": "uv:package:is_project_root", "value": "true" },
+        ])
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "test-git")]
+#[test]
+fn cyclonedx_export_git_subdirectory() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["example-pkg-a @ git+https://github.com/pypa/sample-namespace-packages.git@df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45#subdirectory=pkg_resources/pkg_a"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    context
+        .export()
+        .arg("--format")
+        .arg("cyclonedx1.5")
+        .arg("--preview-features")
+        .arg("sbom-export")
+        .arg("--quiet")
+        .arg("-o")
+        .arg("sbom.json")
+        .assert()
+        .success();
+    let sbom: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        context.temp_dir.child("sbom.json"),
+    )?)?;
+
+    // The subdirectory within the repository is recorded alongside the commit and reference.
+    assert_eq!(sbom["components"][0]["name"], "example-pkg-a");
+    assert_eq!(
+        sbom["components"][0]["properties"],
+        serde_json::json!([
+            { "name": "uv:package:source_type", "value": "git" },
+            { "name": "uv:package:is_direct", "value": "true" },
+            { "name": "uv:git:commit", "value": "df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45" },
+            { "name": "uv:git:reference", "value": "df7530eeb8fa0cb7dbb8ecb28363e8e36bfa2f45" },
+            { "name": "uv:git:subdirectory", "value": "pkg_resources/pkg_a" },
+        ])
+    );
+
+    Ok(())
+}
+
 #[test]
 fn pylock_toml_filter_by_requires_python() -> Result<()> {
     let context = uv_test::test_context!("3.12");